@@ -1,82 +1,176 @@
 //! Main application state and UI.
 
-use crate::arduino::{self, ArduinoConfig, ArduinoHandle};
-use crate::protocol::{ArduinoMessage, CoreToUnityMessage, Finger, SessionStats, TapEvent, UnityToCoreMessage};
-use crate::unity::{self, UnityServerConfig, UnityServerHandle};
+use crate::calibration::{CalibrationCurve, CalibrationPoint};
+use crate::component::{
+    Component, InspectorDirection, InspectorPanel, LogPanel, PendingSessionAction, SensorPanel, SessionControl,
+    SessionPanel, StatsPanel, UIEvent,
+};
+use crate::config::AppConfig;
+use crate::protocol::{ArduinoMessage, CoreToArduinoMessage, CoreToUnityMessage, Finger, TapEvent, UnityToCoreMessage};
+use crate::sensor::{self, ConnectionState, SensorBackend, SensorBackendKind, SensorConnection};
+use crate::state::{CompletedSession, PersistedState};
+use crate::supervisor::{ConnectionSupervisor, LinkState};
+use crate::telemetry::{self, TelemetryConfig, TelemetryEvent, TelemetryHandle};
+use crate::toast::{ToastSeverity, ToastStack};
+use crate::unity::{self, BinaryOpcode, UnityPeerStatus, UnityServerConfig, UnityServerHandle};
 use chrono::Utc;
 use eframe::egui;
-use std::collections::VecDeque;
-
-/// Maximum number of sensor readings to keep in history.
-const SENSOR_HISTORY_SIZE: usize = 200;
-
-/// Pressure threshold to detect a tap.
-const TAP_THRESHOLD: f32 = 0.3;
+use std::path::Path;
+
+/// User-editable settings, e.g. last-used port, thresholds, UI prefs.
+const CONFIG_FILE: &str = "config.yaml";
+/// Accumulated session history.
+const STATE_FILE: &str = "state.json";
+
+/// Capabilities reported by the connected device's `ArduinoMessage::Descriptor`,
+/// shown in Settings. The feature-report form's fields are not derived from it.
+#[derive(Debug, Clone)]
+struct DeviceDescriptor {
+    firmware_version: String,
+    num_fingers: u8,
+    adc_max: u16,
+}
 
 /// Main application state.
 pub struct TactilisApp {
     // Connection state
-    arduino_config: ArduinoConfig,
-    arduino_handle: Option<ArduinoHandle>,
-    available_ports: Vec<String>,
+    sensor_backend_kind: SensorBackendKind,
+    sensor_backend: Box<dyn SensorBackend>,
+    sensor_connection: Option<Box<dyn SensorConnection>>,
+    sensor_connection_state: Option<ConnectionState>,
+    sensor_supervisor: ConnectionSupervisor,
+    available_devices: Vec<String>,
+    selected_device: String,
+    /// Capabilities reported by the connected device, if it has sent a
+    /// `Descriptor` since connecting.
+    device_descriptor: Option<DeviceDescriptor>,
+    /// Feature-report form state, pushed to the device via
+    /// `CoreToArduinoMessage::SetFeatureReport` when the user clicks Send.
+    feature_report_threshold: f32,
+    feature_report_sample_rate_hz: u32,
+    feature_report_index_enabled: bool,
+    feature_report_middle_enabled: bool,
 
     unity_config: UnityServerConfig,
     unity_handle: Option<UnityServerHandle>,
     unity_connected: bool,
+    unity_supervisor: ConnectionSupervisor,
+    /// Connected Unity client count, refreshed each repaint from
+    /// `UnityServerState::peers`.
+    unity_peer_count: usize,
+    /// Most recent round-trip time reported by `UnityPeerStatus::Latency`,
+    /// for any peer. `None` until the first heartbeat pong comes back.
+    unity_latest_latency_ms: Option<u64>,
 
-    // Tokio runtime for async operations
-    runtime: tokio::runtime::Runtime,
+    telemetry_config: TelemetryConfig,
+    telemetry_handle: Option<TelemetryHandle>,
 
-    // Sensor data
-    index_pressure: f32,
-    middle_pressure: f32,
-    index_history: VecDeque<f32>,
-    middle_history: VecDeque<f32>,
+    // Persistence
+    config: AppConfig,
+    state: PersistedState,
 
-    // Tap detection state
-    index_was_pressed: bool,
-    middle_was_pressed: bool,
+    // Tokio runtime for async operations
+    runtime: tokio::runtime::Runtime,
 
-    // Session state
-    session_active: bool,
-    session_stats: SessionStats,
-    tap_log: Vec<TapEvent>,
+    /// Origin for `TapEvent::monotonic_us`, so tap ordering within a
+    /// `TapBatch` survives even when two taps share a millisecond-resolution
+    /// wall-clock `timestamp`.
+    start_instant: std::time::Instant,
+    /// Incremented once per `TapBatch` sent (not once per tap), so a gap in
+    /// the sequence on the Unity side means a dropped/buffered batch rather
+    /// than an ordinary empty poll cycle.
+    tap_sequence: u64,
+
+    // UI components: each owns its own slice of state and reacts to
+    // `UIEvent`s dispatched below, rather than the app reaching in directly.
+    sensor_panel: SensorPanel,
+    session_panel: SessionPanel,
+    stats_panel: StatsPanel,
+    log_panel: LogPanel,
+    inspector_panel: InspectorPanel,
 
     // UI state
-    status_messages: VecDeque<String>,
+    toasts: ToastStack,
+    show_settings: bool,
+    show_calibration: bool,
+    show_inspector: bool,
+    /// Which finger's curve the calibration editor is showing.
+    calibration_finger: Finger,
 }
 
 impl TactilisApp {
     pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
         let runtime = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
 
-        let mut app = Self {
-            arduino_config: ArduinoConfig::default(),
-            arduino_handle: None,
-            available_ports: Vec::new(),
+        let config = AppConfig::load(Path::new(CONFIG_FILE)).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load {}: {}, using defaults", CONFIG_FILE, e);
+            AppConfig::default()
+        });
+        let state = PersistedState::load(Path::new(STATE_FILE)).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load {}: {}, starting with empty history", STATE_FILE, e);
+            PersistedState::default()
+        });
 
-            unity_config: UnityServerConfig::default(),
+        let sensor_backend_kind = SensorBackendKind::from_id(&config.sensor_backend);
+        let sensor_backend = sensor::build_backend(sensor_backend_kind, &config);
+        let selected_device = config.last_sensor_device.clone().unwrap_or_default();
+        let unity_config = UnityServerConfig {
+            host: config.unity_host.clone(),
+            port: config.unity_port,
+            udp: config.unity_udp_port.map(|port| unity::UdpTransportConfig {
+                bind_addr: std::net::SocketAddr::new(config.unity_host.parse().unwrap_or_else(|_| {
+                    std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED)
+                }), port),
+            }),
+            ..Default::default()
+        };
+
+        let mut app = Self {
+            sensor_backend_kind,
+            sensor_backend,
+            sensor_connection: None,
+            sensor_connection_state: None,
+            sensor_supervisor: ConnectionSupervisor::default(),
+            available_devices: Vec::new(),
+            selected_device,
+            device_descriptor: None,
+            feature_report_threshold: config.tap_thresholds.index,
+            feature_report_sample_rate_hz: 100,
+            feature_report_index_enabled: true,
+            feature_report_middle_enabled: true,
+
+            unity_config,
             unity_handle: None,
             unity_connected: false,
+            unity_supervisor: ConnectionSupervisor::default(),
+            unity_peer_count: 0,
+            unity_latest_latency_ms: None,
 
-            runtime,
+            telemetry_config: TelemetryConfig::default(),
+            telemetry_handle: None,
 
-            index_pressure: 0.0,
-            middle_pressure: 0.0,
-            index_history: VecDeque::with_capacity(SENSOR_HISTORY_SIZE),
-            middle_history: VecDeque::with_capacity(SENSOR_HISTORY_SIZE),
+            config,
+            state,
 
-            index_was_pressed: false,
-            middle_was_pressed: false,
+            runtime,
+
+            start_instant: std::time::Instant::now(),
+            tap_sequence: 0,
 
-            session_active: false,
-            session_stats: SessionStats::default(),
-            tap_log: Vec::new(),
+            sensor_panel: SensorPanel::default(),
+            session_panel: SessionPanel::default(),
+            stats_panel: StatsPanel::default(),
+            log_panel: LogPanel::default(),
+            inspector_panel: InspectorPanel::default(),
 
-            status_messages: VecDeque::with_capacity(50),
+            toasts: ToastStack::default(),
+            show_settings: false,
+            show_calibration: false,
+            show_inspector: false,
+            calibration_finger: Finger::Index,
         };
 
-        app.refresh_ports();
+        app.refresh_devices();
         app.log_status("Tactilis Dashboard initialized");
 
         // Auto-start Unity server
@@ -85,39 +179,73 @@ impl TactilisApp {
         app
     }
 
+    /// Fans `event` out to every component. Most events only mean something
+    /// to one or two panels; each `handle_event` just returns `false` for the
+    /// rest, same as an unmatched branch of a big `match` would.
+    fn dispatch(&mut self, event: UIEvent) {
+        self.sensor_panel.handle_event(&event);
+        self.session_panel.handle_event(&event);
+        self.stats_panel.handle_event(&event);
+        self.log_panel.handle_event(&event);
+    }
+
     fn log_status(&mut self, msg: &str) {
         let timestamp = Utc::now().format("%H:%M:%S");
-        self.status_messages.push_front(format!("[{}] {}", timestamp, msg));
-        if self.status_messages.len() > 50 {
-            self.status_messages.pop_back();
-        }
+        self.dispatch(UIEvent::StatusMessage(format!("[{}] {}", timestamp, msg)));
         tracing::info!("{}", msg);
     }
 
-    fn refresh_ports(&mut self) {
-        self.available_ports = arduino::list_available_ports();
+    /// Like `log_status`, but also pops a timed toast — for moments worth
+    /// surfacing outside the scrolling log: connection changes, target hits,
+    /// session boundaries, errors.
+    fn notify(&mut self, msg: &str, severity: ToastSeverity) {
+        self.log_status(msg);
+        self.toasts.push(msg, severity);
     }
 
-    fn connect_arduino(&mut self) {
-        if self.arduino_config.port_name.is_empty() {
-            self.log_status("Please select a serial port");
-            return;
-        }
+    fn refresh_devices(&mut self) {
+        self.available_devices = self.sensor_backend.list_devices();
+    }
+
+    /// Switches the active backend, rebuilding it from the current config
+    /// (so e.g. a changed baud rate or UDP port takes effect immediately).
+    fn select_backend(&mut self, kind: SensorBackendKind) {
+        self.sensor_backend_kind = kind;
+        self.sensor_backend = sensor::build_backend(kind, &self.config);
+        self.selected_device.clear();
+        self.refresh_devices();
+
+        self.config.sensor_backend = kind.id().to_string();
+        self.persist_config();
+    }
+
+    fn connect_sensor(&mut self) {
+        let device = Some(self.selected_device.as_str()).filter(|d| !d.is_empty());
+
+        match self.sensor_backend.connect(device) {
+            Ok(connection) => {
+                self.sensor_connection = Some(connection);
+                self.sensor_supervisor.set_connecting();
+                self.notify(&format!("Connected via {}", self.sensor_backend_kind.label()), ToastSeverity::Success);
 
-        match arduino::connect(self.arduino_config.clone()) {
-            Ok(handle) => {
-                self.arduino_handle = Some(handle);
-                self.log_status(&format!("Connected to Arduino on {}", self.arduino_config.port_name));
+                self.config.last_sensor_device = device.map(str::to_string);
+                self.persist_config();
             }
             Err(e) => {
-                self.log_status(&format!("Failed to connect: {}", e));
+                self.notify(&format!("Failed to connect: {}", e), ToastSeverity::Error);
             }
         }
     }
 
-    fn disconnect_arduino(&mut self) {
-        self.arduino_handle = None;
-        self.log_status("Disconnected from Arduino");
+    fn disconnect_sensor(&mut self) {
+        if let Some(connection) = self.sensor_connection.take() {
+            if let Err(e) = connection.shutdown() {
+                tracing::warn!("Sensor connection exited with error: {}", e);
+            }
+        }
+        self.sensor_connection_state = None;
+        self.sensor_supervisor.set_disconnected();
+        self.notify("Disconnected", ToastSeverity::Warning);
     }
 
     fn start_unity_server(&mut self) {
@@ -138,90 +266,180 @@ impl TactilisApp {
         }
     }
 
-    fn process_arduino_messages(&mut self) {
-        // Collect messages first to avoid borrow issues
-        let messages: Vec<ArduinoMessage> = self
-            .arduino_handle
-            .as_ref()
-            .map(|h| h.receiver.try_iter().collect())
-            .unwrap_or_default();
+    /// Starts the optional MQTT telemetry bridge, mirroring sensor readings,
+    /// taps, and session stats to an external broker. Unlike the Unity
+    /// server, this is off by default: most setups don't run a broker, and
+    /// enabling it when nobody's listening would just be silent retries.
+    fn start_telemetry(&mut self) {
+        let handle = telemetry::start(self.telemetry_config.clone(), self.runtime.handle());
+        self.telemetry_handle = Some(handle);
+        self.log_status(&format!(
+            "MQTT telemetry connecting to {}:{}",
+            self.telemetry_config.host, self.telemetry_config.port
+        ));
+    }
+
+    fn stop_telemetry(&mut self) {
+        self.telemetry_handle = None;
+        self.log_status("MQTT telemetry stopped");
+    }
+
+    /// Mirrors an event onto the MQTT bridge if it's running; a no-op otherwise.
+    fn publish_telemetry(&self, event: TelemetryEvent) {
+        if let Some(handle) = &self.telemetry_handle {
+            handle.publish(event);
+        }
+    }
+
+    /// Writes `config.yaml` back out. Called whenever the settings panel or
+    /// a connection action (e.g. picking a port) changes something worth
+    /// remembering across restarts.
+    fn persist_config(&mut self) {
+        if let Err(e) = self.config.save(Path::new(CONFIG_FILE)) {
+            self.log_status(&format!("Failed to save {}: {}", CONFIG_FILE, e));
+        }
+    }
+
+    /// Writes `state.json` back out. Called after each completed session and
+    /// on clean shutdown, so history survives a restart.
+    fn persist_state(&mut self) {
+        if let Err(e) = self.state.save(Path::new(STATE_FILE)) {
+            self.log_status(&format!("Failed to save {}: {}", STATE_FILE, e));
+        }
+    }
+
+    fn process_sensor_messages(&mut self) {
+        let Some(connection) = self.sensor_connection.as_mut() else { return };
+        let messages = connection.try_recv();
+        let new_states = connection.try_recv_state();
+
+        for state in new_states {
+            let severity = match &state {
+                ConnectionState::Connected { .. } => ToastSeverity::Success,
+                ConnectionState::Reconnecting { .. } => ToastSeverity::Warning,
+                ConnectionState::Scanning | ConnectionState::Probing { .. } => ToastSeverity::Info,
+            };
+            self.notify(&connection_state_message(&state), severity);
+            self.sensor_connection_state = Some(state);
+        }
+        self.tick_sensor_supervisor();
+
+        // Taps detected across this whole batch of drained messages (one
+        // sensor poll cycle) are coalesced into a single `TapBatch` below,
+        // rather than sent to Unity one WebSocket frame per tap.
+        let mut taps_this_cycle = Vec::new();
 
         for msg in messages {
             match msg {
                 ArduinoMessage::Sensor(reading) => {
-                    match reading.finger {
-                        Finger::Index => {
-                            self.index_pressure = reading.pressure;
-                            self.index_history.push_back(reading.pressure);
-                            if self.index_history.len() > SENSOR_HISTORY_SIZE {
-                                self.index_history.pop_front();
-                            }
+                    self.publish_telemetry(TelemetryEvent::Sensor(reading.clone()));
+
+                    let (curve, tap_threshold) = match reading.finger {
+                        Finger::Index => (&self.config.index_calibration, self.config.tap_thresholds.index),
+                        Finger::Middle => (&self.config.middle_calibration, self.config.tap_thresholds.middle),
+                    };
+                    let pressure = curve.apply(reading.pressure);
+
+                    self.inspector_panel.record(
+                        InspectorDirection::SensorIn,
+                        Some(reading.finger),
+                        false,
+                        format!("raw={:.3} pressure={:.3}", reading.pressure, pressure),
+                    );
 
-                            // Tap detection
-                            let pressed = reading.pressure > TAP_THRESHOLD;
-                            if pressed && !self.index_was_pressed {
-                                self.on_tap_detected(Finger::Index, reading.pressure);
-                            }
-                            self.index_was_pressed = pressed;
-                        }
-                        Finger::Middle => {
-                            self.middle_pressure = reading.pressure;
-                            self.middle_history.push_back(reading.pressure);
-                            if self.middle_history.len() > SENSOR_HISTORY_SIZE {
-                                self.middle_history.pop_front();
-                            }
+                    self.dispatch(UIEvent::SensorReading {
+                        finger: reading.finger,
+                        raw: reading.pressure,
+                        pressure,
+                        tap_threshold,
+                        history_capacity: self.config.ui.sensor_history_size,
+                    });
 
-                            let pressed = reading.pressure > TAP_THRESHOLD;
-                            if pressed && !self.middle_was_pressed {
-                                self.on_tap_detected(Finger::Middle, reading.pressure);
-                            }
-                            self.middle_was_pressed = pressed;
-                        }
+                    if let Some((finger, pressure)) = self.sensor_panel.take_tap() {
+                        taps_this_cycle.push(self.on_tap_detected(finger, pressure));
                     }
 
-                    // Forward sensor state to Unity
-                    self.send_to_unity(CoreToUnityMessage::SensorState {
-                        index_pressure: self.index_pressure,
-                        middle_pressure: self.middle_pressure,
-                    });
+                    // Forward sensor state to Unity as a packed binary frame —
+                    // this is a continuous, high-rate stream, so it skips the
+                    // JSON path `send_to_unity` uses for one-off events.
+                    self.send_sensor_frame_to_unity(
+                        self.sensor_panel.pressure(Finger::Index),
+                        self.sensor_panel.pressure(Finger::Middle),
+                    );
                 }
                 ArduinoMessage::Ready { firmware_version } => {
-                    self.log_status(&format!("Arduino ready (firmware: {})", firmware_version));
+                    self.notify(&format!("Arduino ready (firmware: {})", firmware_version), ToastSeverity::Success);
+                }
+                ArduinoMessage::Descriptor { firmware_version, num_fingers, adc_max } => {
+                    self.notify(
+                        &format!("Device descriptor: firmware {}, {} finger(s), ADC range 0-{}", firmware_version, num_fingers, adc_max),
+                        ToastSeverity::Info,
+                    );
+                    self.device_descriptor = Some(DeviceDescriptor { firmware_version, num_fingers, adc_max });
                 }
                 ArduinoMessage::Error { message } => {
-                    self.log_status(&format!("Arduino error: {}", message));
+                    self.inspector_panel.record(InspectorDirection::SensorIn, None, true, message.clone());
+                    self.notify(&format!("Arduino error: {}", message), ToastSeverity::Error);
                 }
             }
         }
+
+        if !taps_this_cycle.is_empty() {
+            self.tap_sequence += 1;
+            let sequence = self.tap_sequence;
+            self.send_to_unity(CoreToUnityMessage::TapBatch { sequence, taps: taps_this_cycle });
+        }
     }
 
     fn process_unity_messages(&mut self) {
         // Update connection state
         if let Some(handle) = &self.unity_handle {
             if let Ok(state) = handle.state.try_read() {
-                self.unity_connected = state.connected;
+                self.unity_connected = state.is_connected();
+                self.unity_peer_count = state.peers().len();
             }
         }
+        self.tick_unity_supervisor();
 
         // Collect messages first to avoid borrow issues
         let mut messages = Vec::new();
+        let mut statuses = Vec::new();
         if let Some(handle) = &mut self.unity_handle {
             while let Ok(msg) = handle.receiver.try_recv() {
                 messages.push(msg);
             }
+            // Must be drained every tick: `status_tx.send(...).await` on the
+            // server side blocks once this bounded channel fills, which would
+            // stall the heartbeat task's own timeout/disconnect handling.
+            while let Ok(status) = handle.status_receiver.try_recv() {
+                statuses.push(status);
+            }
+        }
+
+        for status in statuses {
+            match status {
+                UnityPeerStatus::Latency { latency_ms, .. } => {
+                    self.unity_latest_latency_ms = Some(latency_ms);
+                }
+                UnityPeerStatus::HeartbeatTimedOut { peer } => {
+                    self.notify(&format!("Unity client {} timed out", peer), ToastSeverity::Warning);
+                }
+            }
         }
 
         for msg in messages {
-            match msg {
+            self.inspector_panel.record(
+                InspectorDirection::UnityIn,
+                None,
+                false,
+                serde_json::to_string(&msg).unwrap_or_else(|e| format!("<unserializable: {}>", e)),
+            );
+            match &msg {
                 UnityToCoreMessage::Ready { client_version } => {
-                    self.log_status(&format!("Unity client connected (v{})", client_version));
+                    self.notify(&format!("Unity client connected (v{})", client_version), ToastSeverity::Success);
                 }
                 UnityToCoreMessage::TargetHit { target_id, .. } => {
-                    self.log_status(&format!("Target {} hit", target_id));
-                    if self.session_active {
-                        self.session_stats.total_taps += 1;
-                        self.session_stats.successful_taps += 1;
-                    }
+                    self.notify(&format!("Target {} hit", target_id), ToastSeverity::Info);
                 }
                 UnityToCoreMessage::RequestSessionStart => {
                     self.start_session();
@@ -230,61 +448,322 @@ impl TactilisApp {
                     self.end_session();
                 }
                 UnityToCoreMessage::Disconnect => {
-                    self.log_status("Unity client disconnected");
+                    self.notify("Unity client disconnected", ToastSeverity::Warning);
                 }
             }
+            // `StatsPanel` does its own active-session gating on `TargetHit`,
+            // so this is safe to dispatch unconditionally after the match above.
+            self.dispatch(UIEvent::UnityMessage(msg));
         }
     }
 
-    fn on_tap_detected(&mut self, finger: Finger, pressure: f32) {
+    /// Mirrors `sensor_connection_state` into `sensor_supervisor`. Backends
+    /// that don't report lifecycle transitions (everything but Arduino's auto
+    /// backend) leave `sensor_connection_state` at `None` once connected,
+    /// which we treat as "online and nothing further to say" — they have no
+    /// way to tell us a device went away, so there's nothing to retry either.
+    fn tick_sensor_supervisor(&mut self) {
+        match &self.sensor_connection_state {
+            Some(ConnectionState::Connected { .. }) | None => {
+                self.sensor_supervisor.set_online();
+            }
+            Some(ConnectionState::Scanning) | Some(ConnectionState::Probing { .. }) => {
+                self.sensor_supervisor.set_connecting();
+            }
+            Some(ConnectionState::Reconnecting { .. }) => {
+                // Arduino's own connect_auto manager already owns the
+                // backoff/retry here; we just mirror its state once per
+                // transition so the rest of the app has one `LinkState` to
+                // reason about (calling `set_lost` every repaint would churn
+                // its attempt counter far faster than real retries happen).
+                if !matches!(self.sensor_supervisor.state(), LinkState::Lost { .. }) {
+                    self.sensor_supervisor.set_lost();
+                }
+            }
+        }
+    }
+
+    /// Unlike the sensor side, the Unity link has no backend-owned reconnect
+    /// loop: the server just listens, and a dropped client has to come back
+    /// on its own. This is what `on_unity_online` exists for (losing a client
+    /// runs no hook of its own) — and, for the case the server itself isn't
+    /// even running (startup failure), this does drive a real reconnect
+    /// attempt on the usual backoff.
+    fn tick_unity_supervisor(&mut self) {
+        if self.unity_handle.is_none() {
+            // Only mark the transition once; otherwise every repaint would
+            // bump the attempt counter regardless of whether a retry is
+            // actually due.
+            if !matches!(self.unity_supervisor.state(), LinkState::Lost { .. }) {
+                self.unity_supervisor.set_lost();
+            } else if self.unity_supervisor.due_for_retry() {
+                self.notify("Retrying Unity server startup", ToastSeverity::Info);
+                self.start_unity_server();
+                // `start_unity_server` may have failed again; either way the
+                // old Lost/backoff reading is stale now, so recompute it.
+                if self.unity_handle.is_some() {
+                    self.unity_supervisor.set_connecting();
+                } else {
+                    self.unity_supervisor.set_lost();
+                }
+            }
+            return;
+        }
+
+        if self.unity_connected {
+            if self.unity_supervisor.set_online() {
+                self.on_unity_online();
+            }
+            return;
+        }
+
+        // Listening, but no client right now: a first-time wait looks like
+        // `Connecting`; losing a client that was previously `Online` looks
+        // like `Lost` so the backoff/attempt display means something.
+        if self.unity_supervisor.state() == LinkState::Online {
+            self.unity_supervisor.set_lost();
+        } else if matches!(self.unity_supervisor.state(), LinkState::Disconnected) {
+            self.unity_supervisor.set_connecting();
+        }
+    }
+
+    /// Fires once, on the transition back into `Online`: a freshly
+    /// (re)connected Unity client has missed whatever happened while it was
+    /// away. Everything sent via `send_to_unity` while it was gone is
+    /// already queued in `UnityServerState`'s replay buffer and gets
+    /// flushed to the new connection automatically; the active session
+    /// isn't, since it's state rather than a one-off message, so
+    /// re-announce it explicitly.
+    fn on_unity_online(&mut self) {
+        if let Some(session_id) = self.session_panel.session_id().map(str::to_string) {
+            self.send_to_unity(CoreToUnityMessage::SessionStart { session_id });
+        }
+    }
+
+    /// Handles everything local to one detected tap (internal dispatch,
+    /// telemetry, the biofeedback buzz) and returns the `TapEvent` for the
+    /// caller to fold into this cycle's `TapBatch` rather than forwarding it
+    /// to Unity itself — `process_sensor_messages` may call this more than
+    /// once per cycle, and those all need to land in the same batch.
+    fn on_tap_detected(&mut self, finger: Finger, pressure: f32) -> TapEvent {
         let event = TapEvent {
             finger,
             pressure_peak: pressure,
             duration_ms: 0, // TODO: Calculate actual duration
             timestamp: Utc::now(),
+            monotonic_us: self.start_instant.elapsed().as_micros() as u64,
         };
 
-        self.tap_log.push(event.clone());
-        if self.tap_log.len() > 100 {
-            self.tap_log.remove(0);
-        }
+        self.dispatch(UIEvent::TapDetected(event.clone()));
+        self.publish_telemetry(TelemetryEvent::Tap(event.clone()));
+
+        // Close the biofeedback loop with a buzz, rather than relying on AR visuals alone
+        self.send_to_arduino(CoreToArduinoMessage::Buzz {
+            finger,
+            intensity: pressure,
+            duration_ms: 50,
+        });
 
-        // Forward to Unity
-        self.send_to_unity(CoreToUnityMessage::TapDetected(event));
+        event
     }
 
-    fn send_to_unity(&self, msg: CoreToUnityMessage) {
+    /// Sends to every connected Unity client. `UnityServerHandle::send`
+    /// buffers the message itself if none is connected right now, so e.g. a
+    /// `SessionStart` sent before Unity finishes its handshake isn't simply
+    /// lost.
+    fn send_to_unity(&mut self, msg: CoreToUnityMessage) {
+        self.inspector_panel.record(
+            InspectorDirection::UnityOut,
+            None,
+            false,
+            serde_json::to_string(&msg).unwrap_or_else(|e| format!("<unserializable: {}>", e)),
+        );
         if let Some(handle) = &self.unity_handle {
             let _ = handle.send(msg);
         }
     }
 
-    fn start_session(&mut self) {
-        self.session_active = true;
-        self.session_stats = SessionStats::default();
-        self.tap_log.clear();
+    /// Pushes a sensor reading to every connected Unity client as a packed
+    /// `BinaryOpcode::SensorFrame` (two little-endian `f32`s), bypassing JSON
+    /// serialization for this continuous, high-rate stream. Unlike
+    /// `send_to_unity`, a send with nobody connected is just skipped — the
+    /// next reading is moments away, so there's nothing worth buffering.
+    fn send_sensor_frame_to_unity(&mut self, index_pressure: f32, middle_pressure: f32) {
+        if let Some(handle) = &self.unity_handle {
+            let mut payload = Vec::with_capacity(8);
+            payload.extend_from_slice(&index_pressure.to_le_bytes());
+            payload.extend_from_slice(&middle_pressure.to_le_bytes());
+            let _ = handle.send_binary(BinaryOpcode::SensorFrame, &payload);
+        }
+    }
 
+    fn send_to_arduino(&self, msg: CoreToArduinoMessage) {
+        if let Some(connection) = &self.sensor_connection {
+            connection.send_command(msg);
+        }
+    }
+
+    fn start_session(&mut self) {
         let session_id = Utc::now().format("%Y%m%d_%H%M%S").to_string();
-        self.log_status(&format!("Session started: {}", session_id));
+        self.dispatch(UIEvent::SessionControl(SessionControl::Start(session_id.clone())));
+        self.notify(&format!("Session started: {}", session_id), ToastSeverity::Info);
 
         self.send_to_unity(CoreToUnityMessage::SessionStart { session_id });
     }
 
     fn end_session(&mut self) {
-        self.session_active = false;
-        self.log_status("Session ended");
-
-        self.send_to_unity(CoreToUnityMessage::SessionEnd {
-            stats: self.session_stats.clone(),
+        let session_id = self.session_panel.take_session_id().unwrap_or_default();
+        self.dispatch(UIEvent::SessionControl(SessionControl::End));
+        self.notify("Session ended", ToastSeverity::Info);
+
+        let stats = self.stats_panel.stats().clone();
+        self.send_to_unity(CoreToUnityMessage::SessionEnd { stats: stats.clone() });
+        self.publish_telemetry(TelemetryEvent::SessionStats(stats.clone()));
+
+        self.state.sessions.push(CompletedSession {
+            session_id,
+            ended_at: Utc::now(),
+            stats,
+            taps: self.stats_panel.tap_log().to_vec(),
         });
+        self.persist_state();
+    }
+}
+
+/// Status-log line for a `ConnectionState` transition.
+fn connection_state_message(state: &ConnectionState) -> String {
+    match state {
+        ConnectionState::Scanning => "Scanning for Arduino ports...".to_string(),
+        ConnectionState::Probing { port_name } => format!("Probing {}...", port_name),
+        ConnectionState::Connected { port_name } => format!("Connected to Arduino on {}", port_name),
+        ConnectionState::Reconnecting { attempt, retry_in } => {
+            format!("Lost Arduino connection, retrying in {:.0}s (attempt {})", retry_in.as_secs_f32(), attempt)
+        }
+    }
+}
+
+/// Color/label for the top-panel status dot while `connect_auto` is active.
+fn connection_state_indicator(state: &ConnectionState) -> (egui::Color32, String) {
+    match state {
+        ConnectionState::Scanning => (egui::Color32::YELLOW, "Searching...".to_string()),
+        ConnectionState::Probing { port_name } => (egui::Color32::YELLOW, format!("Probing {}", port_name)),
+        ConnectionState::Connected { port_name } => (egui::Color32::GREEN, format!("Connected ({})", port_name)),
+        ConnectionState::Reconnecting { attempt, .. } => {
+            (egui::Color32::RED, format!("Reconnecting (attempt {})", attempt))
+        }
+    }
+}
+
+/// Color/label for the top-panel Unity status dot, from the generic
+/// `LinkState` the supervisor tracks it with. `server_up` disambiguates
+/// `Lost` (the websocket server itself isn't running vs. just waiting for a
+/// client to reconnect), since the supervisor doesn't know which link owns it.
+fn unity_link_indicator(state: LinkState, server_up: bool) -> (egui::Color32, String) {
+    match state {
+        LinkState::Disconnected => (egui::Color32::GRAY, "Server down".to_string()),
+        LinkState::Connecting => (egui::Color32::YELLOW, "Waiting for client".to_string()),
+        LinkState::Online => (egui::Color32::GREEN, "Connected".to_string()),
+        LinkState::Lost { attempt, retry_in } if server_up => {
+            (egui::Color32::RED, format!("Client lost ({:.0}s, attempt {})", retry_in.as_secs_f32(), attempt))
+        }
+        LinkState::Lost { attempt, retry_in } => {
+            (egui::Color32::RED, format!("Server down, retrying in {:.0}s (attempt {})", retry_in.as_secs_f32(), attempt))
+        }
     }
 }
 
+/// Inserts a new breakpoint at the midpoint of the curve's widest gap, so
+/// "Add point" always gives the user something useful to drag rather than a
+/// point stacked on an existing one.
+fn add_calibration_point(curve: &mut CalibrationCurve) {
+    if curve.points.len() < 2 {
+        return;
+    }
+    let widest = curve
+        .points
+        .windows(2)
+        .enumerate()
+        .max_by(|(_, a), (_, b)| (a[1].raw - a[0].raw).total_cmp(&(b[1].raw - b[0].raw)));
+
+    if let Some((i, window)) = widest {
+        let mid = CalibrationPoint {
+            raw: (window[0].raw + window[1].raw) / 2.0,
+            out: (window[0].out + window[1].out) / 2.0,
+        };
+        curve.points.insert(i + 1, mid);
+    }
+}
+
+/// Draws `curve` as a draggable-point `egui_plot` editor, with a vertical
+/// marker for the finger's current raw reading. Breakpoints are dragged by
+/// nearest-point adoption on drag start (egui_plot has no built-in
+/// draggable-point widget), tracked via a bit of `egui::Memory` keyed on
+/// `id_source` so drag state survives across frames.
+fn draw_calibration_editor(ui: &mut egui::Ui, curve: &mut CalibrationCurve, raw_value: f32, id_source: &str) {
+    let drag_id = ui.id().with(id_source).with("dragging");
+
+    egui_plot::Plot::new(id_source)
+        .height(220.0)
+        .include_x(0.0)
+        .include_x(1.0)
+        .include_y(0.0)
+        .include_y(1.0)
+        .show(ui, |plot_ui| {
+            let line_points: egui_plot::PlotPoints =
+                curve.points.iter().map(|p| [p.raw as f64, p.out as f64]).collect();
+            plot_ui.line(egui_plot::Line::new(line_points).name("Curve").color(egui::Color32::LIGHT_BLUE));
+
+            let marker_points: egui_plot::PlotPoints =
+                curve.points.iter().map(|p| [p.raw as f64, p.out as f64]).collect();
+            plot_ui.points(egui_plot::Points::new(marker_points).radius(5.0).color(egui::Color32::WHITE));
+
+            plot_ui.vline(
+                egui_plot::VLine::new(raw_value as f64)
+                    .name("Current raw reading")
+                    .color(egui::Color32::GRAY)
+                    .style(egui_plot::LineStyle::dotted_dense()),
+            );
+
+            let pointer = plot_ui.pointer_coordinate();
+            let response = plot_ui.response();
+
+            if response.drag_started() {
+                if let Some(pointer) = pointer {
+                    if let Some((closest, _)) = curve
+                        .points
+                        .iter()
+                        .enumerate()
+                        .map(|(i, p)| (i, ((p.raw as f64 - pointer.x).powi(2) + (p.out as f64 - pointer.y).powi(2)).sqrt()))
+                        .min_by(|a, b| a.1.total_cmp(&b.1))
+                    {
+                        plot_ui.ctx().data_mut(|d| d.insert_temp(drag_id, closest));
+                    }
+                }
+            }
+
+            if response.dragged() {
+                let dragging: Option<usize> = plot_ui.ctx().data(|d| d.get_temp(drag_id));
+                if let (Some(index), Some(pointer)) = (dragging, pointer) {
+                    if let Some(point) = curve.points.get_mut(index) {
+                        point.raw = pointer.x.clamp(0.0, 1.0) as f32;
+                        point.out = pointer.y.clamp(0.0, 1.0) as f32;
+                    }
+                }
+            }
+
+            if response.drag_stopped() {
+                plot_ui.ctx().data_mut(|d| d.remove::<usize>(drag_id));
+                curve.sort();
+            }
+        });
+}
+
 impl eframe::App for TactilisApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         // Process incoming messages
-        self.process_arduino_messages();
+        self.process_sensor_messages();
         self.process_unity_messages();
+        self.dispatch(UIEvent::Tick);
 
         // Request continuous repaint for real-time updates
         ctx.request_repaint();
@@ -295,35 +774,58 @@ impl eframe::App for TactilisApp {
                 ui.heading("Tactilis Dashboard");
                 ui.separator();
 
-                // Arduino connection
-                ui.label("Arduino:");
-                if self.arduino_handle.is_some() {
-                    ui.colored_label(egui::Color32::GREEN, "â—");
+                // Sensor connection
+                ui.label("Sensor:");
+                if self.sensor_connection.is_some() {
+                    let (color, label) = match &self.sensor_connection_state {
+                        Some(state) => connection_state_indicator(state),
+                        None => (egui::Color32::GREEN, "Connected".to_string()),
+                    };
+                    ui.colored_label(color, "â—");
+                    ui.label(label);
                     if ui.button("Disconnect").clicked() {
-                        self.disconnect_arduino();
+                        self.disconnect_sensor();
                     }
                 } else {
                     ui.colored_label(egui::Color32::RED, "â—");
-                    egui::ComboBox::from_id_salt("port_select")
-                        .selected_text(if self.arduino_config.port_name.is_empty() {
-                            "Select port..."
+
+                    let previous_kind = self.sensor_backend_kind;
+                    egui::ComboBox::from_id_salt("backend_select")
+                        .selected_text(self.sensor_backend_kind.label())
+                        .show_ui(ui, |ui| {
+                            for kind in SensorBackendKind::ALL {
+                                ui.selectable_value(&mut self.sensor_backend_kind, kind, kind.label());
+                            }
+                        });
+                    if self.sensor_backend_kind != previous_kind {
+                        self.select_backend(self.sensor_backend_kind);
+                    }
+
+                    egui::ComboBox::from_id_salt("device_select")
+                        .selected_text(if self.selected_device.is_empty() {
+                            "Select device..."
                         } else {
-                            &self.arduino_config.port_name
+                            &self.selected_device
                         })
                         .show_ui(ui, |ui| {
-                            for port in &self.available_ports {
-                                ui.selectable_value(
-                                    &mut self.arduino_config.port_name,
-                                    port.clone(),
-                                    port,
-                                );
+                            for device in &self.available_devices {
+                                ui.selectable_value(&mut self.selected_device, device.clone(), device);
                             }
                         });
-                    if ui.button("ðŸ”„").on_hover_text("Refresh ports").clicked() {
-                        self.refresh_ports();
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.selected_device)
+                            .hint_text("or type a port/name/file/host:port")
+                            .desired_width(120.0),
+                    );
+                    if ui.button("ðŸ”„").on_hover_text("Refresh devices").clicked() {
+                        self.refresh_devices();
                     }
-                    if ui.button("Connect").clicked() {
-                        self.connect_arduino();
+                    if ui
+                        .button("Connect")
+                        .on_hover_text("Leave the device blank to auto-discover, where the backend supports it")
+                        .clicked()
+                    {
+                        self.connect_sensor();
                     }
                 }
 
@@ -331,153 +833,248 @@ impl eframe::App for TactilisApp {
 
                 // Unity connection status
                 ui.label("Unity:");
-                if self.unity_connected {
-                    ui.colored_label(egui::Color32::GREEN, "â— Connected");
+                let (color, label) = unity_link_indicator(self.unity_supervisor.state(), self.unity_handle.is_some());
+                ui.colored_label(color, "â—");
+                ui.label(label);
+                if self.unity_peer_count > 0 {
+                    ui.label(format!("({} client{})", self.unity_peer_count, if self.unity_peer_count == 1 { "" } else { "s" }));
+                }
+                if let Some(latency_ms) = self.unity_latest_latency_ms {
+                    ui.label(format!("{} ms", latency_ms));
+                }
+
+                ui.separator();
+
+                // MQTT telemetry bridge, off by default (most setups don't run a broker)
+                ui.label("Telemetry:");
+                if self.telemetry_handle.is_some() {
+                    ui.colored_label(egui::Color32::GREEN, "â— Publishing");
+                    if ui.button("Stop").clicked() {
+                        self.stop_telemetry();
+                    }
                 } else {
-                    ui.colored_label(egui::Color32::YELLOW, "â— Waiting");
+                    ui.colored_label(egui::Color32::GRAY, "â— Off");
+                    if ui.button("Start").on_hover_text(format!(
+                        "Publish to {}:{}",
+                        self.telemetry_config.host, self.telemetry_config.port
+                    )).clicked() {
+                        self.start_telemetry();
+                    }
+                }
+
+                ui.separator();
+
+                if ui.button("âš™ Settings").clicked() {
+                    self.show_settings = !self.show_settings;
+                }
+
+                if ui.button("ðŸ“ˆ Calibration").clicked() {
+                    self.show_calibration = !self.show_calibration;
+                }
+
+                if ui.button("ðŸ” Inspector").clicked() {
+                    self.show_inspector = !self.show_inspector;
+                }
+
+                let basic_mode_label = if self.config.ui.basic_mode { "Full view" } else { "Basic mode" };
+                if ui
+                    .button(basic_mode_label)
+                    .on_hover_text("Collapse to live bars, session control, and stats only — no history graph")
+                    .clicked()
+                {
+                    self.config.ui.basic_mode = !self.config.ui.basic_mode;
+                    self.persist_config();
                 }
             });
         });
 
-        // Left panel with sensor visualization
-        egui::SidePanel::left("sensor_panel")
-            .min_width(300.0)
-            .show(ctx, |ui| {
-                ui.heading("Sensor Data");
+        if self.show_settings {
+            let mut open = self.show_settings;
+            egui::Window::new("Settings").open(&mut open).show(ctx, |ui| {
+                ui.heading("Connection");
+                egui::Grid::new("settings_connection_grid").num_columns(2).show(ui, |ui| {
+                    ui.label("Serial baud rate:");
+                    ui.add(egui::DragValue::new(&mut self.config.baud_rate));
+                    ui.end_row();
+
+                    ui.label("Unity host:");
+                    ui.text_edit_singleline(&mut self.config.unity_host);
+                    ui.end_row();
+
+                    ui.label("Unity port:");
+                    ui.add(egui::DragValue::new(&mut self.config.unity_port));
+                    ui.end_row();
+                });
+                ui.label("Unity host/port take effect the next time the dashboard starts.");
+
                 ui.separator();
 
-                // Current pressure values
-                ui.horizontal(|ui| {
+                ui.heading("Tap thresholds");
+                egui::Grid::new("settings_threshold_grid").num_columns(2).show(ui, |ui| {
                     ui.label("Index finger:");
-                    ui.add(
-                        egui::ProgressBar::new(self.index_pressure)
-                            .text(format!("{:.1}%", self.index_pressure * 100.0)),
-                    );
-                });
+                    ui.add(egui::Slider::new(&mut self.config.tap_thresholds.index, 0.0..=1.0));
+                    ui.end_row();
 
-                ui.horizontal(|ui| {
                     ui.label("Middle finger:");
-                    ui.add(
-                        egui::ProgressBar::new(self.middle_pressure)
-                            .text(format!("{:.1}%", self.middle_pressure * 100.0)),
-                    );
+                    ui.add(egui::Slider::new(&mut self.config.tap_thresholds.middle, 0.0..=1.0));
+                    ui.end_row();
                 });
 
                 ui.separator();
 
-                // Pressure history graph
-                ui.label("Pressure History");
-
-                let index_points: egui_plot::PlotPoints = self
-                    .index_history
-                    .iter()
-                    .enumerate()
-                    .map(|(i, &p)| [i as f64, p as f64])
-                    .collect();
-
-                let middle_points: egui_plot::PlotPoints = self
-                    .middle_history
-                    .iter()
-                    .enumerate()
-                    .map(|(i, &p)| [i as f64, p as f64])
-                    .collect();
-
-                egui_plot::Plot::new("pressure_plot")
-                    .height(200.0)
-                    .include_y(0.0)
-                    .include_y(1.0)
-                    .show(ui, |plot_ui| {
-                        plot_ui.line(
-                            egui_plot::Line::new(index_points)
-                                .name("Index")
-                                .color(egui::Color32::LIGHT_BLUE),
-                        );
-                        plot_ui.line(
-                            egui_plot::Line::new(middle_points)
-                                .name("Middle")
-                                .color(egui::Color32::LIGHT_GREEN),
-                        );
-                        // Threshold line
-                        plot_ui.hline(
-                            egui_plot::HLine::new(TAP_THRESHOLD as f64)
-                                .name("Tap threshold")
-                                .color(egui::Color32::RED)
-                                .style(egui_plot::LineStyle::dashed_dense()),
-                        );
-                    });
-            });
+                ui.heading("Display");
+                ui.horizontal(|ui| {
+                    ui.label("Pressure history length:");
+                    ui.add(egui::DragValue::new(&mut self.config.ui.sensor_history_size).clamp_range(10..=2000));
+                });
 
-        // Central panel with session controls and stats
-        egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading("Session");
-            ui.separator();
+                ui.separator();
 
-            ui.horizontal(|ui| {
-                if self.session_active {
-                    if ui.button("â¹ End Session").clicked() {
-                        self.end_session();
+                ui.heading("Device");
+                match &self.device_descriptor {
+                    Some(descriptor) => {
+                        ui.label(format!(
+                            "Firmware {} · {} finger(s) · ADC range 0-{}",
+                            descriptor.firmware_version, descriptor.num_fingers, descriptor.adc_max
+                        ));
                     }
-                    ui.colored_label(egui::Color32::GREEN, "Session Active");
-                } else {
-                    if ui.button("â–¶ Start Session").clicked() {
-                        self.start_session();
+                    None => {
+                        ui.label("No device descriptor received yet.");
                     }
                 }
-            });
+                egui::Grid::new("settings_feature_report_grid").num_columns(2).show(ui, |ui| {
+                    ui.label("Pressure threshold:");
+                    ui.add(egui::Slider::new(&mut self.feature_report_threshold, 0.0..=1.0));
+                    ui.end_row();
+
+                    ui.label("Sample rate (Hz):");
+                    ui.add(egui::DragValue::new(&mut self.feature_report_sample_rate_hz).clamp_range(1..=1000));
+                    ui.end_row();
+
+                    ui.label("Index finger enabled:");
+                    ui.checkbox(&mut self.feature_report_index_enabled, "");
+                    ui.end_row();
+
+                    ui.label("Middle finger enabled:");
+                    ui.checkbox(&mut self.feature_report_middle_enabled, "");
+                    ui.end_row();
+                });
+                if ui
+                    .button("Send to device")
+                    .on_hover_text("Push this feature report to the connected device over serial")
+                    .clicked()
+                {
+                    self.send_to_arduino(CoreToArduinoMessage::SetFeatureReport {
+                        pressure_threshold: self.feature_report_threshold,
+                        sample_rate_hz: self.feature_report_sample_rate_hz,
+                        index_enabled: self.feature_report_index_enabled,
+                        middle_enabled: self.feature_report_middle_enabled,
+                    });
+                    self.log_status("Sent feature report to device");
+                }
 
-            ui.separator();
+                ui.separator();
 
-            // Session statistics
-            ui.heading("Statistics");
-            egui::Grid::new("stats_grid").show(ui, |ui| {
-                ui.label("Total taps:");
-                ui.label(self.session_stats.total_taps.to_string());
-                ui.end_row();
+                if ui.button("Save").clicked() {
+                    self.persist_config();
+                    self.log_status("Settings saved");
+                }
+            });
+            self.show_settings = open;
+        }
 
-                ui.label("Successful taps:");
-                ui.label(self.session_stats.successful_taps.to_string());
-                ui.end_row();
+        if self.show_calibration {
+            let mut open = self.show_calibration;
+            egui::Window::new("Calibration").open(&mut open).default_width(420.0).show(ctx, |ui| {
+                ui.label("Drag points to adjust the raw -> pressure mapping for each finger.");
 
-                ui.label("Avg reaction time:");
-                ui.label(format!("{:.0} ms", self.session_stats.average_reaction_time_ms));
-                ui.end_row();
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.calibration_finger, Finger::Index, "Index");
+                    ui.selectable_value(&mut self.calibration_finger, Finger::Middle, "Middle");
+                });
+                ui.separator();
 
-                ui.label("Avg accuracy:");
-                ui.label(format!("{:.1}%", self.session_stats.average_accuracy * 100.0));
-                ui.end_row();
-            });
+                let (curve, raw) = match self.calibration_finger {
+                    Finger::Index => (&mut self.config.index_calibration, self.sensor_panel.raw(Finger::Index)),
+                    Finger::Middle => (&mut self.config.middle_calibration, self.sensor_panel.raw(Finger::Middle)),
+                };
 
-            ui.separator();
-
-            // Recent taps log
-            ui.heading("Recent Taps");
-            egui::ScrollArea::vertical()
-                .max_height(150.0)
-                .show(ui, |ui| {
-                    for tap in self.tap_log.iter().rev().take(10) {
-                        ui.horizontal(|ui| {
-                            let finger_str = match tap.finger {
-                                Finger::Index => "Index",
-                                Finger::Middle => "Middle",
-                            };
-                            ui.label(tap.timestamp.format("%H:%M:%S").to_string());
-                            ui.label(finger_str);
-                            ui.label(format!("{:.0}%", tap.pressure_peak * 100.0));
-                        });
+                draw_calibration_editor(ui, curve, raw, "calibration_plot");
+
+                ui.horizontal(|ui| {
+                    if ui.button("+ Add point").clicked() {
+                        add_calibration_point(curve);
+                    }
+                    if ui
+                        .button("- Remove point")
+                        .on_hover_text("Removes the last interior point; keeps the two endpoints")
+                        .clicked()
+                        && curve.points.len() > 2
+                    {
+                        curve.points.remove(curve.points.len() - 2);
+                    }
+                    if ui.button("Reset").clicked() {
+                        *curve = CalibrationCurve::default();
                     }
                 });
 
-            ui.separator();
+                ui.separator();
+                if ui.button("Save").clicked() {
+                    self.persist_config();
+                    self.log_status("Calibration saved");
+                }
+            });
+            self.show_calibration = open;
+        }
 
-            // Status log
-            ui.heading("Status Log");
-            egui::ScrollArea::vertical()
-                .max_height(150.0)
-                .show(ui, |ui| {
-                    for msg in &self.status_messages {
-                        ui.label(msg);
-                    }
-                });
-        });
+        if self.show_inspector {
+            let mut open = self.show_inspector;
+            egui::Window::new("Inspector").open(&mut open).default_width(520.0).show(ctx, |ui| {
+                self.inspector_panel.draw(ui);
+            });
+            self.show_inspector = open;
+        }
+
+        if self.config.ui.basic_mode {
+            // Single compact column: live bars, session control, core stats —
+            // no history plot, no scroll areas, no side panel.
+            egui::CentralPanel::default().show(ctx, |ui| {
+                self.sensor_panel.draw_compact(ui);
+                ui.separator();
+                self.session_panel.draw(ui);
+                ui.separator();
+                self.stats_panel.draw_compact(ui);
+            });
+        } else {
+            // Left panel with sensor visualization
+            egui::SidePanel::left("sensor_panel")
+                .min_width(300.0)
+                .show(ctx, |ui| self.sensor_panel.draw(ui));
+
+            // Central panel with session controls and stats
+            egui::CentralPanel::default().show(ctx, |ui| {
+                self.session_panel.draw(ui);
+                ui.separator();
+                self.stats_panel.draw(ui);
+                ui.separator();
+                self.log_panel.draw(ui);
+            });
+        }
+
+        if let Some(action) = self.session_panel.take_pending_action() {
+            match action {
+                PendingSessionAction::Start => self.start_session(),
+                PendingSessionAction::End => self.end_session(),
+            }
+        }
+
+        self.toasts.show(ctx);
+    }
+
+    /// Flushes config and session history to disk on a clean shutdown, so a
+    /// crash is the only way to lose calibration or session data between runs.
+    fn on_exit(&mut self) {
+        self.persist_config();
+        self.persist_state();
     }
 }
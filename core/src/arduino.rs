@@ -1,13 +1,47 @@
-//! Arduino serial communication handler.
+//! Arduino communication handler.
 //!
-//! Manages USB serial connection to the Arduino, parsing sensor readings
-//! and forwarding them to the main application.
+//! Manages the link to the Arduino (USB serial, or BLE for wireless glove
+//! prototypes), parsing sensor readings and forwarding them to the main
+//! application. Wrapped by `sensor::ArduinoSerialBackend`/`BluetoothGloveBackend`
+//! so it plugs into `SensorBackend` alongside non-Arduino sensor sources.
 
-use crate::protocol::{ArduinoMessage, Finger, SensorReading};
-use std::io::{BufRead, BufReader};
-use std::sync::mpsc;
-use std::time::Duration;
+use crate::backoff::reconnect_backoff;
+use crate::protocol::{ArduinoMessage, CoreToArduinoMessage, Finger, SensorReading};
+use btleplug::api::{Central, Manager as _, Peripheral as _, ScanFilter};
+use btleplug::platform::Manager;
+use futures_util::StreamExt;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
 use thiserror::Error;
+use uuid::Uuid;
+
+/// Nordic UART Service, used by the BLE transport.
+const NUS_SERVICE_UUID: Uuid = Uuid::from_u128(0x6e400001_b5a3_f393_e0a9_e50e24dcca9e);
+/// Notify characteristic the peripheral uses to stream data to us.
+const NUS_TX_CHARACTERISTIC_UUID: Uuid = Uuid::from_u128(0x6e400002_b5a3_f393_e0a9_e50e24dcca9e);
+/// Write characteristic we use to push cues (LED/buzz/calibrate) to the peripheral.
+const NUS_RX_CHARACTERISTIC_UUID: Uuid = Uuid::from_u128(0x6e400003_b5a3_f393_e0a9_e50e24dcca9e);
+/// How long to scan for advertising peripherals before giving up.
+const BLE_SCAN_DURATION: Duration = Duration::from_secs(5);
+/// How often a transport thread checks for outbound `CoreToArduinoMessage`s.
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Substrings that a USB-serial port name is expected to contain on the
+/// platforms we support, used to keep unrelated ports (a laptop's built-in
+/// debug UART, a printer's virtual COM port...) out of both the manual
+/// device picker and `connect_auto`'s probe loop.
+const LIKELY_DEVICE_PORT_SUBSTRINGS: &[&str] = &["usbmodem", "usbserial", "ttyACM", "ttyUSB", "COM"];
+/// How long `connect_auto`'s `Probing` state waits for a `Ready` handshake
+/// before giving up on a candidate port.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Read/sleep timeout used to periodically recheck a handle's shutdown flag,
+/// so `shutdown()` unblocks a reader thread within one tick instead of
+/// waiting for EOF or the next incoming line.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// ADC range assumed until an `ArduinoMessage::Descriptor` negotiates a
+/// different one — the original board's 10-bit ADC (0-1023).
+const DEFAULT_ADC_MAX: u16 = 1023;
 
 #[derive(Error, Debug)]
 pub enum ArduinoError {
@@ -19,21 +53,86 @@ pub enum ArduinoError {
     Parse(#[from] serde_json::Error),
     #[error("Port not found: {0}")]
     PortNotFound(String),
+    #[error("BLE error: {0}")]
+    Ble(#[from] btleplug::Error),
+    #[error("BLE device not found: {0}")]
+    BleDeviceNotFound(String),
+    #[error("Arduino communication thread panicked")]
+    ThreadPanicked,
+}
+
+/// Selects which physical link `connect` uses to reach the glove. Both
+/// variants feed the same `ArduinoMessage` stream through `parse_arduino_message`,
+/// so callers (and the rest of Core) don't need to know which one is active.
+#[derive(Clone)]
+pub enum ArduinoTransport {
+    /// USB serial, the original transport.
+    Serial(ArduinoConfig),
+    /// Bluetooth LE via the Nordic UART Service, for wireless glove prototypes.
+    /// `device_name` is matched against the peripheral's advertised local name.
+    Ble { device_name: String },
+    /// A TCP bridge (e.g. a Raspberry Pi wired to the glove) that forwards raw
+    /// Arduino serial bytes over a socket, decoupling the hardware's physical
+    /// location from the machine running Core + Unity.
+    Tcp(TcpConfig),
+}
+
+/// Configuration for the TCP bridge transport.
+#[derive(Clone, Copy)]
+pub struct TcpConfig {
+    pub addr: std::net::SocketAddr,
+    /// Whether to keep retrying the connection after it drops, instead of
+    /// exiting the communication thread.
+    pub reconnect: bool,
 }
 
-/// Lists available serial ports that might be Arduino devices.
+/// Lists available serial ports that might be Arduino devices: every port
+/// the OS reports, filtered down to ones whose name matches a pattern a
+/// USB-serial adapter is expected to use (`/dev/cu.usbmodem*`,
+/// `/dev/ttyACM*`, `COM*`...), so scanning/probing and the manual device
+/// picker aren't cluttered with ports that are never going to be the glove.
 pub fn list_available_ports() -> Vec<String> {
     serialport::available_ports()
         .unwrap_or_default()
         .into_iter()
         .map(|p| p.port_name)
+        .filter(|name| LIKELY_DEVICE_PORT_SUBSTRINGS.iter().any(|pattern| name.contains(pattern)))
         .collect()
 }
 
 /// Handle to the Arduino connection thread.
 pub struct ArduinoHandle {
     pub receiver: mpsc::Receiver<ArduinoMessage>,
-    _thread: std::thread::JoinHandle<()>,
+    /// Sends cues (LED/buzz/calibrate/feature reports) down to the board,
+    /// over whichever transport is active. Serialized as a JSON line, same
+    /// as incoming messages.
+    pub sender: mpsc::Sender<CoreToArduinoMessage>,
+    /// Flipped by `shutdown()` to unblock the thread's poll loop.
+    shutdown: Arc<AtomicBool>,
+    /// Updated by the reader thread when an `ArduinoMessage::Descriptor`
+    /// negotiates a non-default ADC range, and read back by that same
+    /// thread on every subsequent line — shared so a descriptor seen
+    /// mid-session takes effect immediately rather than only on reconnect.
+    adc_max: Arc<AtomicU16>,
+    thread: std::thread::JoinHandle<Result<(), ArduinoError>>,
+}
+
+impl ArduinoHandle {
+    /// Signals the connection thread to stop, waits for it to exit, and
+    /// returns any terminal error it hit. Drops the serial port / TCP socket
+    /// on the way out, so the port is free again (e.g. for the next device
+    /// the user picks) as soon as this returns.
+    pub fn shutdown(self) -> Result<(), ArduinoError> {
+        self.shutdown.store(true, Ordering::Relaxed);
+        drop(self.sender);
+        self.thread.join().unwrap_or(Err(ArduinoError::ThreadPanicked))
+    }
+
+    /// The ADC range currently applied to incoming `SensorReading`s —
+    /// `DEFAULT_ADC_MAX` until a `Descriptor` negotiates otherwise.
+    pub fn adc_max(&self) -> u16 {
+        self.adc_max.load(Ordering::Relaxed)
+    }
 }
 
 /// Configuration for Arduino connection.
@@ -52,63 +151,588 @@ impl Default for ArduinoConfig {
     }
 }
 
-/// Starts the Arduino communication thread.
+/// Starts the Arduino communication thread for the given transport.
 ///
 /// Returns a handle with a receiver for incoming messages.
-pub fn connect(config: ArduinoConfig) -> Result<ArduinoHandle, ArduinoError> {
+pub fn connect(transport: ArduinoTransport) -> Result<ArduinoHandle, ArduinoError> {
+    match transport {
+        ArduinoTransport::Serial(config) => connect_serial(config),
+        ArduinoTransport::Ble { device_name } => connect_ble(device_name),
+        ArduinoTransport::Tcp(config) => connect_tcp(config),
+    }
+}
+
+fn connect_serial(config: ArduinoConfig) -> Result<ArduinoHandle, ArduinoError> {
     let port = serialport::new(&config.port_name, config.baud_rate)
-        .timeout(Duration::from_millis(100))
+        .timeout(SHUTDOWN_POLL_INTERVAL)
         .open()?;
 
     let (sender, receiver) = mpsc::channel();
+    let (cmd_sender, cmd_receiver) = mpsc::channel();
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let thread_shutdown = shutdown.clone();
+    let adc_max = Arc::new(AtomicU16::new(DEFAULT_ADC_MAX));
+    let thread_adc_max = adc_max.clone();
 
     let thread = std::thread::spawn(move || {
-        let mut reader = BufReader::new(port);
-        let mut line_buf = String::new();
-
-        loop {
-            line_buf.clear();
-            match reader.read_line(&mut line_buf) {
-                Ok(0) => break, // EOF
-                Ok(_) => {
-                    let line = line_buf.trim();
-                    if line.is_empty() {
+        let result = run_read_write_loop(port, &sender, &cmd_receiver, &thread_shutdown, &thread_adc_max);
+        tracing::info!("Arduino communication thread exiting");
+        result
+    });
+
+    Ok(ArduinoHandle {
+        receiver,
+        sender: cmd_sender,
+        shutdown,
+        adc_max,
+        thread,
+    })
+}
+
+/// Reads lines from an already-open port, forwarding parsed `ArduinoMessage`s to
+/// `sender`, while writing out anything received on `cmd_receiver`. Runs until
+/// EOF, an IO error, `shutdown` is set, or the receiver is dropped. Shared by
+/// `connect_serial` and `connect_auto`'s connection manager so both transports
+/// stay in sync. The port's read timeout must be no longer than
+/// `SHUTDOWN_POLL_INTERVAL` for `shutdown` to be noticed promptly.
+fn run_read_write_loop(
+    port: Box<dyn serialport::SerialPort>,
+    sender: &mpsc::Sender<ArduinoMessage>,
+    cmd_receiver: &mpsc::Receiver<CoreToArduinoMessage>,
+    shutdown: &AtomicBool,
+    adc_max: &AtomicU16,
+) -> Result<(), ArduinoError> {
+    let mut writer = port.try_clone().map_err(|e| {
+        tracing::error!("Failed to clone serial port for writing: {}", e);
+        ArduinoError::Io(e.into())
+    })?;
+    let mut reader = BufReader::new(port);
+    let mut line_buf = String::new();
+
+    while !shutdown.load(Ordering::Relaxed) {
+        while let Ok(cmd) = cmd_receiver.try_recv() {
+            if let Err(e) = write_serial_command(writer.as_mut(), &cmd) {
+                tracing::warn!("Failed to write Arduino command: {}", e);
+            }
+        }
+
+        line_buf.clear();
+        match reader.read_line(&mut line_buf) {
+            Ok(0) => return Ok(()), // EOF
+            Ok(_) => {
+                let line = line_buf.trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                // Try to parse as JSON message
+                match parse_arduino_message(line, adc_max.load(Ordering::Relaxed)) {
+                    Ok(msg) => {
+                        if let ArduinoMessage::Descriptor { adc_max: negotiated, .. } = &msg {
+                            adc_max.store(*negotiated, Ordering::Relaxed);
+                        }
+                        if sender.send(msg).is_err() {
+                            return Ok(()); // Receiver dropped
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to parse Arduino message: {}", e);
+                        let message = format!("failed to parse {:?}: {}", line, e);
+                        if sender.send(ArduinoMessage::Error { message }).is_err() {
+                            return Ok(()); // Receiver dropped
+                        }
+                    }
+                }
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                continue;
+            }
+            Err(e) => {
+                tracing::error!("Serial read error: {}", e);
+                return Err(e.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a `CoreToArduinoMessage` as a JSON line over the serial port, the
+/// mirror of the JSON lines `parse_arduino_message` reads back.
+fn write_serial_command(writer: &mut dyn serialport::SerialPort, cmd: &CoreToArduinoMessage) -> Result<(), ArduinoError> {
+    let json = serde_json::to_string(cmd)?;
+    writer.write_all(json.as_bytes())?;
+    writer.write_all(b"\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Connects to a TCP bridge instead of opening a local port. Reuses the same
+/// `BufReader` + `read_line` + `parse_arduino_message` loop as `connect_serial`,
+/// just over a `TcpStream`, so a Raspberry Pi wired to the glove can relay
+/// readings to a workstation elsewhere on the LAN.
+fn connect_tcp(config: TcpConfig) -> Result<ArduinoHandle, ArduinoError> {
+    let stream = std::net::TcpStream::connect(config.addr)?;
+
+    let (sender, receiver) = mpsc::channel();
+    let (cmd_sender, cmd_receiver) = mpsc::channel();
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let thread_shutdown = shutdown.clone();
+    let adc_max = Arc::new(AtomicU16::new(DEFAULT_ADC_MAX));
+    let thread_adc_max = adc_max.clone();
+
+    let thread = std::thread::spawn(move || {
+        let mut stream = Some(stream);
+        let mut last_result = Ok(());
+
+        while !thread_shutdown.load(Ordering::Relaxed) {
+            let current = match stream.take() {
+                Some(s) => s,
+                None => match std::net::TcpStream::connect(config.addr) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        tracing::error!("Failed to reconnect TCP bridge {}: {}", config.addr, e);
+                        std::thread::sleep(Duration::from_secs(2));
                         continue;
                     }
+                },
+            };
 
-                    // Try to parse as JSON message
-                    match parse_arduino_message(line) {
-                        Ok(msg) => {
-                            if sender.send(msg).is_err() {
-                                break; // Receiver dropped
-                            }
+            last_result = run_tcp_read_write_loop(current, &sender, &cmd_receiver, &thread_shutdown, &thread_adc_max);
+
+            if !config.reconnect {
+                break;
+            }
+            std::thread::sleep(Duration::from_secs(2));
+        }
+
+        tracing::info!("Arduino TCP bridge thread exiting");
+        last_result
+    });
+
+    Ok(ArduinoHandle {
+        receiver,
+        sender: cmd_sender,
+        shutdown,
+        adc_max,
+        thread,
+    })
+}
+
+/// TCP mirror of `run_read_write_loop`, for the bridge transport. `shutdown`
+/// is rechecked every `SHUTDOWN_POLL_INTERVAL` via the stream's read timeout.
+fn run_tcp_read_write_loop(
+    stream: std::net::TcpStream,
+    sender: &mpsc::Sender<ArduinoMessage>,
+    cmd_receiver: &mpsc::Receiver<CoreToArduinoMessage>,
+    shutdown: &AtomicBool,
+    adc_max: &AtomicU16,
+) -> Result<(), ArduinoError> {
+    stream.set_read_timeout(Some(SHUTDOWN_POLL_INTERVAL))?;
+    let mut writer = stream.try_clone().map_err(|e| {
+        tracing::error!("Failed to clone TCP stream for writing: {}", e);
+        ArduinoError::Io(e)
+    })?;
+    let mut reader = BufReader::new(stream);
+    let mut line_buf = String::new();
+
+    while !shutdown.load(Ordering::Relaxed) {
+        while let Ok(cmd) = cmd_receiver.try_recv() {
+            if let Err(e) = write_tcp_command(&mut writer, &cmd) {
+                tracing::warn!("Failed to write Arduino command over TCP: {}", e);
+            }
+        }
+
+        line_buf.clear();
+        match reader.read_line(&mut line_buf) {
+            Ok(0) => return Ok(()), // Bridge closed the connection
+            Ok(_) => {
+                let line = line_buf.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                match parse_arduino_message(line, adc_max.load(Ordering::Relaxed)) {
+                    Ok(msg) => {
+                        if let ArduinoMessage::Descriptor { adc_max: negotiated, .. } = &msg {
+                            adc_max.store(*negotiated, Ordering::Relaxed);
                         }
-                        Err(e) => {
-                            tracing::warn!("Failed to parse Arduino message: {}", e);
+                        if sender.send(msg).is_err() {
+                            return Ok(());
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to parse Arduino message over TCP: {}", e);
+                        let message = format!("failed to parse {:?}: {}", line, e);
+                        if sender.send(ArduinoMessage::Error { message }).is_err() {
+                            return Ok(());
                         }
                     }
                 }
-                Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => {
-                    continue;
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut || e.kind() == std::io::ErrorKind::WouldBlock => {
+                continue;
+            }
+            Err(e) => {
+                tracing::error!("TCP bridge read error: {}", e);
+                return Err(e.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a `CoreToArduinoMessage` as a JSON line over the TCP bridge, the
+/// mirror of `write_serial_command`.
+fn write_tcp_command(writer: &mut std::net::TcpStream, cmd: &CoreToArduinoMessage) -> Result<(), ArduinoError> {
+    let json = serde_json::to_string(cmd)?;
+    writer.write_all(json.as_bytes())?;
+    writer.write_all(b"\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Connection lifecycle for `connect_auto`'s port-discovery state machine,
+/// broadcast on `ArduinoAutoHandle::state_receiver` so the UI can show
+/// "searching / connected / lost" rather than a binary on/off toggle.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState {
+    Scanning,
+    Probing { port_name: String },
+    Connected { port_name: String },
+    Reconnecting { attempt: u32, retry_in: Duration },
+}
+
+/// Handle to a `connect_auto` connection manager. Unlike `ArduinoHandle`, the
+/// port is not fixed up front: the manager enumerates and probes candidates
+/// itself, and keeps re-probing after a lost connection instead of requiring a
+/// manual reconnect.
+pub struct ArduinoAutoHandle {
+    pub receiver: mpsc::Receiver<ArduinoMessage>,
+    pub sender: mpsc::Sender<CoreToArduinoMessage>,
+    pub state_receiver: mpsc::Receiver<ConnectionState>,
+    /// Flipped by `shutdown()` to unblock the manager's scan/probe/sleep loop.
+    shutdown: Arc<AtomicBool>,
+    /// See `ArduinoHandle::adc_max` — shared the same way, but survives
+    /// across a probe/reconnect cycle rather than resetting to
+    /// `DEFAULT_ADC_MAX` each time a new port is adopted, since the glove
+    /// itself (not the port) is what determines the range.
+    adc_max: Arc<AtomicU16>,
+    thread: std::thread::JoinHandle<()>,
+}
+
+impl ArduinoAutoHandle {
+    /// Signals the connection manager to stop, waits for it to exit, and
+    /// drops whatever port it currently holds. The manager has no single
+    /// terminal error to report (it treats every failure as "retry"), so
+    /// unlike `ArduinoHandle::shutdown` this can't fail.
+    pub fn shutdown(self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        let _ = self.thread.join();
+    }
+
+    /// The ADC range currently applied to incoming `SensorReading`s —
+    /// `DEFAULT_ADC_MAX` until a `Descriptor` negotiates otherwise.
+    pub fn adc_max(&self) -> u16 {
+        self.adc_max.load(Ordering::Relaxed)
+    }
+}
+
+/// Starts the resilient `Disconnected -> Scanning -> Probing -> Connected ->
+/// Reconnecting` connection manager described above, suitable for unattended
+/// sessions where nobody is around to pick a port or restart the app.
+pub fn connect_auto(baud_rate: u32) -> ArduinoAutoHandle {
+    let (sender, receiver) = mpsc::channel();
+    let (cmd_sender, cmd_receiver) = mpsc::channel();
+    let (state_sender, state_receiver) = mpsc::channel();
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let thread_shutdown = shutdown.clone();
+    let adc_max = Arc::new(AtomicU16::new(DEFAULT_ADC_MAX));
+    let thread_adc_max = adc_max.clone();
+
+    let thread = std::thread::spawn(move || {
+        run_connection_manager(baud_rate, &sender, &cmd_receiver, &state_sender, &thread_shutdown, &thread_adc_max);
+    });
+
+    ArduinoAutoHandle {
+        receiver,
+        sender: cmd_sender,
+        state_receiver,
+        shutdown,
+        adc_max,
+        thread,
+    }
+}
+
+fn run_connection_manager(
+    baud_rate: u32,
+    sender: &mpsc::Sender<ArduinoMessage>,
+    cmd_receiver: &mpsc::Receiver<CoreToArduinoMessage>,
+    state_sender: &mpsc::Sender<ConnectionState>,
+    shutdown: &AtomicBool,
+    adc_max: &AtomicU16,
+) {
+    let mut attempt: u32 = 0;
+
+    while !shutdown.load(Ordering::Relaxed) {
+        if state_sender.send(ConnectionState::Scanning).is_err() {
+            return; // Nobody is listening anymore
+        }
+
+        let mut probed = None;
+        for port_name in list_available_ports() {
+            let _ = state_sender.send(ConnectionState::Probing { port_name: port_name.clone() });
+            match probe_port(&port_name, baud_rate, PROBE_TIMEOUT) {
+                Ok(port) => {
+                    probed = Some((port_name, port));
+                    break;
                 }
                 Err(e) => {
-                    tracing::error!("Serial read error: {}", e);
-                    break;
+                    tracing::debug!("Probe of {} failed: {}", port_name, e);
                 }
             }
         }
 
-        tracing::info!("Arduino communication thread exiting");
+        let Some((port_name, port)) = probed else {
+            attempt += 1;
+            let retry_in = reconnect_backoff(attempt);
+            if state_sender.send(ConnectionState::Reconnecting { attempt, retry_in }).is_err() {
+                return;
+            }
+            sleep_with_shutdown_check(retry_in, shutdown);
+            continue;
+        };
+
+        attempt = 0;
+        tracing::info!("Adopted Arduino on {}", port_name);
+        if state_sender.send(ConnectionState::Connected { port_name }).is_err() {
+            return;
+        }
+
+        if let Err(e) = run_read_write_loop(port, sender, cmd_receiver, shutdown, adc_max) {
+            tracing::warn!("Arduino connection lost: {}", e);
+        }
+
+        if shutdown.load(Ordering::Relaxed) {
+            return;
+        }
+
+        attempt += 1;
+        let retry_in = reconnect_backoff(attempt);
+        if state_sender.send(ConnectionState::Reconnecting { attempt, retry_in }).is_err() {
+            return;
+        }
+        sleep_with_shutdown_check(retry_in, shutdown);
+    }
+}
+
+/// Sleeps for `duration`, waking up every `SHUTDOWN_POLL_INTERVAL` to recheck
+/// `shutdown` instead of blocking the whole span.
+fn sleep_with_shutdown_check(duration: Duration, shutdown: &AtomicBool) {
+    let deadline = Instant::now() + duration;
+    while !shutdown.load(Ordering::Relaxed) && Instant::now() < deadline {
+        std::thread::sleep(SHUTDOWN_POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())));
+    }
+}
+
+/// Opens `port_name` and waits up to `timeout` for an `ArduinoMessage::Ready`
+/// handshake, adopting the port only once a valid one arrives. This is what
+/// lets users plug in a glove without knowing (or choosing) its port name.
+fn probe_port(port_name: &str, baud_rate: u32, timeout: Duration) -> Result<Box<dyn serialport::SerialPort>, ArduinoError> {
+    let port = serialport::new(port_name, baud_rate)
+        .timeout(Duration::from_millis(100))
+        .open()?;
+
+    let mut reader = BufReader::new(port.try_clone()?);
+    let mut line_buf = String::new();
+    let deadline = Instant::now() + timeout;
+
+    while Instant::now() < deadline {
+        line_buf.clear();
+        match reader.read_line(&mut line_buf) {
+            Ok(0) => return Err(ArduinoError::PortNotFound(port_name.to_string())),
+            Ok(_) => {
+                let line = line_buf.trim();
+                if !line.is_empty() {
+                    if let Ok(ArduinoMessage::Ready { .. }) = parse_arduino_message(line, DEFAULT_ADC_MAX) {
+                        return Ok(port);
+                    }
+                }
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => continue,
+            Err(e) => return Err(ArduinoError::Io(e)),
+        }
+    }
+
+    Err(ArduinoError::PortNotFound(port_name.to_string()))
+}
+
+/// Starts the BLE communication thread. Unlike `connect_serial`, the actual
+/// scan/connect happens inside the spawned thread (it can take several
+/// seconds), so failures are reported as an `ArduinoMessage::Error` on the
+/// channel rather than as a `Result` from this function.
+fn connect_ble(device_name: String) -> Result<ArduinoHandle, ArduinoError> {
+    let (sender, receiver) = mpsc::channel();
+    let (cmd_sender, cmd_receiver) = mpsc::channel();
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let thread_shutdown = shutdown.clone();
+    let adc_max = Arc::new(AtomicU16::new(DEFAULT_ADC_MAX));
+    let thread_adc_max = adc_max.clone();
+
+    let thread = std::thread::spawn(move || {
+        let rt = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+            Ok(rt) => rt,
+            Err(e) => {
+                tracing::error!("Failed to start BLE runtime: {}", e);
+                return Err(ArduinoError::Io(e));
+            }
+        };
+
+        let result =
+            rt.block_on(run_ble_link(&device_name, sender.clone(), cmd_receiver, &thread_shutdown, &thread_adc_max));
+        if let Err(ref e) = result {
+            tracing::error!("BLE link error: {}", e);
+            let _ = sender.send(ArduinoMessage::Error { message: e.to_string() });
+        }
+
+        tracing::info!("Arduino BLE communication thread exiting");
+        result
     });
 
     Ok(ArduinoHandle {
         receiver,
-        _thread: thread,
+        sender: cmd_sender,
+        shutdown,
+        adc_max,
+        thread,
     })
 }
 
-/// Parse a line from Arduino into a message.
-fn parse_arduino_message(line: &str) -> Result<ArduinoMessage, ArduinoError> {
+/// Scans for a peripheral advertising the Nordic UART Service, subscribes to
+/// its TX characteristic, and feeds the notification byte stream through the
+/// same line-buffering + `parse_arduino_message` path the serial transport uses.
+/// Outbound `CoreToArduinoMessage`s are polled from `cmd_receiver` and written
+/// to the RX characteristic every `COMMAND_POLL_INTERVAL`.
+async fn run_ble_link(
+    device_name: &str,
+    sender: mpsc::Sender<ArduinoMessage>,
+    cmd_receiver: mpsc::Receiver<CoreToArduinoMessage>,
+    shutdown: &AtomicBool,
+    adc_max: &AtomicU16,
+) -> Result<(), ArduinoError> {
+    let manager = Manager::new().await?;
+    let adapter = manager
+        .adapters()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ArduinoError::BleDeviceNotFound("no BLE adapter available".to_string()))?;
+
+    adapter.start_scan(ScanFilter::default()).await?;
+    tokio::time::sleep(BLE_SCAN_DURATION).await;
+
+    let mut peripheral = None;
+    for candidate in adapter.peripherals().await? {
+        if let Ok(Some(props)) = candidate.properties().await {
+            let matches_name = props.local_name.as_deref() == Some(device_name);
+            let offers_nus = props.services.contains(&NUS_SERVICE_UUID);
+            // `device_name` comes from `BluetoothGloveBackend::connect`, which
+            // requires it non-empty precisely so the user can pick a glove —
+            // honor that by requiring the name match. `offers_nus` alone is
+            // only a fallback for the (currently unused) empty-name case, not
+            // an alternative way to match a *named* candidate.
+            let is_candidate = if device_name.is_empty() { offers_nus } else { matches_name };
+            if is_candidate {
+                peripheral = Some(candidate);
+                break;
+            }
+        }
+    }
+    let peripheral = peripheral.ok_or_else(|| ArduinoError::BleDeviceNotFound(device_name.to_string()))?;
+
+    peripheral.connect().await?;
+    peripheral.discover_services().await?;
+
+    let characteristics = peripheral.characteristics();
+    let tx_characteristic = characteristics
+        .iter()
+        .find(|c| c.uuid == NUS_TX_CHARACTERISTIC_UUID)
+        .ok_or_else(|| ArduinoError::BleDeviceNotFound(format!("{device_name}: no TX characteristic")))?
+        .clone();
+    let rx_characteristic = characteristics
+        .iter()
+        .find(|c| c.uuid == NUS_RX_CHARACTERISTIC_UUID)
+        .ok_or_else(|| ArduinoError::BleDeviceNotFound(format!("{device_name}: no RX characteristic")))?
+        .clone();
+
+    peripheral.subscribe(&tx_characteristic).await?;
+    let mut notifications = peripheral.notifications().await?;
+    let mut command_poll = tokio::time::interval(COMMAND_POLL_INTERVAL);
+
+    let mut line_buf = String::new();
+    loop {
+        tokio::select! {
+            notification = notifications.next() => {
+                let Some(notification) = notification else { break };
+                for byte in notification.value {
+                    if byte == b'\n' {
+                        let line = line_buf.trim();
+                        if !line.is_empty() {
+                            match parse_arduino_message(line, adc_max.load(Ordering::Relaxed)) {
+                                Ok(msg) => {
+                                    if let ArduinoMessage::Descriptor { adc_max: negotiated, .. } = &msg {
+                                        adc_max.store(*negotiated, Ordering::Relaxed);
+                                    }
+                                    if sender.send(msg).is_err() {
+                                        return Ok(()); // Receiver dropped
+                                    }
+                                }
+                                Err(e) => {
+                                    tracing::warn!("Failed to parse Arduino message over BLE: {}", e);
+                                }
+                            }
+                        }
+                        line_buf.clear();
+                    } else {
+                        line_buf.push(byte as char);
+                    }
+                }
+            }
+            _ = command_poll.tick() => {
+                if shutdown.load(Ordering::Relaxed) {
+                    return Ok(());
+                }
+                while let Ok(cmd) = cmd_receiver.try_recv() {
+                    if let Err(e) = write_ble_command(&peripheral, &rx_characteristic, &cmd).await {
+                        tracing::warn!("Failed to write Arduino BLE command: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a `CoreToArduinoMessage` to the RX characteristic, the BLE mirror of
+/// `write_serial_command`.
+async fn write_ble_command(
+    peripheral: &btleplug::platform::Peripheral,
+    characteristic: &btleplug::api::Characteristic,
+    cmd: &CoreToArduinoMessage,
+) -> Result<(), ArduinoError> {
+    let mut payload = serde_json::to_vec(cmd)?;
+    payload.push(b'\n');
+    peripheral
+        .write(characteristic, &payload, btleplug::api::WriteType::WithoutResponse)
+        .await?;
+    Ok(())
+}
+
+/// Parse a line from Arduino into a message. `adc_max` is the currently
+/// negotiated ADC range (see `ArduinoMessage::Descriptor`), used to
+/// normalize a comma-format `Sensor` reading's raw value.
+fn parse_arduino_message(line: &str, adc_max: u16) -> Result<ArduinoMessage, ArduinoError> {
     // First try JSON parsing
     if line.starts_with('{') {
         return Ok(serde_json::from_str(line)?);
@@ -139,8 +763,7 @@ fn parse_arduino_message(line: &str) -> Result<ArduinoMessage, ArduinoError> {
             ))
         })?;
 
-        // Normalize 10-bit ADC (0-1023) to 0.0-1.0
-        let pressure = raw_value as f32 / 1023.0;
+        let pressure = raw_value as f32 / adc_max as f32;
 
         return Ok(ArduinoMessage::Sensor(SensorReading {
             finger,
@@ -168,7 +791,7 @@ mod tests {
 
     #[test]
     fn test_parse_simple_format() {
-        let msg = parse_arduino_message("I,512,1000").unwrap();
+        let msg = parse_arduino_message("I,512,1000", DEFAULT_ADC_MAX).unwrap();
         if let ArduinoMessage::Sensor(reading) = msg {
             assert_eq!(reading.finger, Finger::Index);
             assert!((reading.pressure - 0.5).abs() < 0.01);
@@ -178,13 +801,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_simple_format_uses_negotiated_adc_max() {
+        let msg = parse_arduino_message("I,2047,1000", 4095).unwrap();
+        if let ArduinoMessage::Sensor(reading) = msg {
+            assert!((reading.pressure - 0.5).abs() < 0.01);
+        } else {
+            panic!("Expected Sensor message");
+        }
+    }
+
     #[test]
     fn test_parse_ready() {
-        let msg = parse_arduino_message("READY v1.0.0").unwrap();
+        let msg = parse_arduino_message("READY v1.0.0", DEFAULT_ADC_MAX).unwrap();
         if let ArduinoMessage::Ready { firmware_version } = msg {
             assert_eq!(firmware_version, "v1.0.0");
         } else {
             panic!("Expected Ready message");
         }
     }
+
+    #[test]
+    fn test_parse_descriptor_json() {
+        let msg = parse_arduino_message(
+            r#"{"type":"descriptor","firmware_version":"v2.0.0","num_fingers":2,"adc_max":4095}"#,
+            DEFAULT_ADC_MAX,
+        )
+        .unwrap();
+        match msg {
+            ArduinoMessage::Descriptor { firmware_version, num_fingers, adc_max } => {
+                assert_eq!(firmware_version, "v2.0.0");
+                assert_eq!(num_fingers, 2);
+                assert_eq!(adc_max, 4095);
+            }
+            _ => panic!("Expected Descriptor message"),
+        }
+    }
 }
@@ -0,0 +1,18 @@
+//! Shared exponential backoff for reconnect loops — used by `arduino`'s
+//! serial auto-reconnect (`connect_auto`) and `supervisor`'s generic link
+//! tracking, both of
+//! which want the same "give up gracefully, don't hammer a dead link" shape.
+
+use std::time::Duration;
+
+/// Backoff before the first reconnect attempt; doubles per attempt up to
+/// `MAX_RECONNECT_BACKOFF`.
+pub const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+pub const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Exponential backoff starting at `INITIAL_RECONNECT_BACKOFF`, doubling per
+/// attempt, capped at `MAX_RECONNECT_BACKOFF`.
+pub fn reconnect_backoff(attempt: u32) -> Duration {
+    let factor = 1u32.checked_shl(attempt.saturating_sub(1).min(8)).unwrap_or(u32::MAX);
+    (INITIAL_RECONNECT_BACKOFF.saturating_mul(factor)).min(MAX_RECONNECT_BACKOFF)
+}
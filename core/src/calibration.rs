@@ -0,0 +1,80 @@
+//! Per-finger calibration curves mapping a raw sensor reading to normalized
+//! pressure.
+//!
+//! Without this, `SensorReading::pressure` is compared directly against a
+//! tap threshold, which assumes every sensor reads 0.0 at rest and responds
+//! identically — untrue in practice, since each flex sensor has its own rest
+//! value and response. A `CalibrationCurve` is an ordered list of
+//! breakpoints that `apply` interpolates between piecewise-linearly.
+
+use serde::{Deserialize, Serialize};
+
+/// One breakpoint in a calibration curve: `raw` is an incoming reading,
+/// `out` the normalized pressure it should map to.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationPoint {
+    pub raw: f32,
+    pub out: f32,
+}
+
+/// Piecewise-linear calibration curve for one finger. `points` should stay
+/// sorted by `raw`; call `sort` after an editor drag might have crossed two
+/// points.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationCurve {
+    pub points: Vec<CalibrationPoint>,
+}
+
+impl Default for CalibrationCurve {
+    /// Flat 0.0 -> 1.0 identity mapping, i.e. no calibration applied.
+    fn default() -> Self {
+        Self {
+            points: vec![
+                CalibrationPoint { raw: 0.0, out: 0.0 },
+                CalibrationPoint { raw: 1.0, out: 1.0 },
+            ],
+        }
+    }
+}
+
+impl CalibrationCurve {
+    /// Maps `raw` through the curve: finds the bracketing breakpoint pair
+    /// and linearly interpolates between them. Clamps to the first/last
+    /// `out` for readings outside the curve's range, and falls back to the
+    /// left point's `out` on a zero-width segment instead of dividing by
+    /// zero. A curve with fewer than two points can't interpolate, so `raw`
+    /// passes through unchanged.
+    pub fn apply(&self, raw: f32) -> f32 {
+        let points = &self.points;
+        if points.len() < 2 {
+            return raw;
+        }
+
+        if raw <= points[0].raw {
+            return points[0].out;
+        }
+        let last = points[points.len() - 1];
+        if raw >= last.raw {
+            return last.out;
+        }
+
+        for window in points.windows(2) {
+            let (a, b) = (window[0], window[1]);
+            if raw >= a.raw && raw <= b.raw {
+                let span = b.raw - a.raw;
+                if span.abs() < f32::EPSILON {
+                    return a.out;
+                }
+                return a.out + (raw - a.raw) / span * (b.out - a.out);
+            }
+        }
+
+        raw
+    }
+
+    /// Re-sorts `points` by `raw`, e.g. after a breakpoint is dragged past
+    /// its neighbor in the calibration editor.
+    pub fn sort(&mut self) {
+        self.points.sort_by(|a, b| a.raw.total_cmp(&b.raw));
+    }
+}
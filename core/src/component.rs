@@ -0,0 +1,612 @@
+//! Event-driven UI components.
+//!
+//! `TactilisApp::update` used to be one function polling both message
+//! queues, running tap detection, mutating session state, and drawing every
+//! panel — hard to extend without wading through all of it, and impossible
+//! to unit-test without an egui context. This splits the panels (sensor
+//! readout, session controls, stats, status log) out from under it: each
+//! owns its own slice of state behind the `Component` trait, reacting to a
+//! normalized `UIEvent` instead of reaching into `TactilisApp` directly.
+//! `TactilisApp` still owns the actual connections and decides what an event
+//! means for persistence/telemetry/cross-link forwarding — that's app-level
+//! orchestration, not any one panel's job — but it no longer has to also be
+//! the thing tap detection and session bookkeeping run inside of.
+
+use crate::protocol::{Finger, SessionStats, TapEvent, UnityToCoreMessage};
+use chrono::Utc;
+use eframe::egui;
+
+/// Normalized event dispatched to every component once per occurrence,
+/// regardless of which backend or thread it originated from.
+#[derive(Debug, Clone)]
+pub enum UIEvent {
+    /// A calibrated sensor reading, alongside enough context (raw value,
+    /// current threshold, history capacity) for a component to do its own
+    /// tap-edge detection and history bookkeeping without reaching back
+    /// into `AppConfig`.
+    SensorReading {
+        finger: Finger,
+        raw: f32,
+        pressure: f32,
+        tap_threshold: f32,
+        history_capacity: usize,
+    },
+    TapDetected(TapEvent),
+    /// A message from the Unity client, forwarded verbatim — components
+    /// that care (stats, on `TargetHit`) match on it themselves rather than
+    /// the app pre-digesting every message shape it might ever carry.
+    UnityMessage(UnityToCoreMessage),
+    SessionControl(SessionControl),
+    /// A line for the status log, already formatted.
+    StatusMessage(String),
+    /// Fired once per frame, for components with time-based state that
+    /// doesn't have its own discrete event to react to.
+    Tick,
+}
+
+#[derive(Debug, Clone)]
+pub enum SessionControl {
+    Start(String),
+    End,
+}
+
+/// One self-contained panel. Implementors own whatever state they need to
+/// remember between frames; `TactilisApp` only holds the list and forwards
+/// events/draw calls to it.
+pub trait Component {
+    /// React to `event`, updating internal state. Returns `true` if the
+    /// event meant something to this component — mostly useful for tests
+    /// asserting "did this event do anything" without an egui context.
+    fn handle_event(&mut self, event: &UIEvent) -> bool;
+
+    /// Render this component's slice of the UI.
+    fn draw(&mut self, ui: &mut egui::Ui);
+}
+
+/// Live sensor readout: current pressure, history graph, and the tap-edge
+/// detection that drives `TapDetected`. Keeping detection here (rather than
+/// in `TactilisApp`) is what makes it testable without egui — feed it
+/// `SensorReading`s and read `take_tap()`.
+pub struct SensorPanel {
+    index_pressure: f32,
+    middle_pressure: f32,
+    index_raw: f32,
+    middle_raw: f32,
+    index_threshold: f32,
+    middle_threshold: f32,
+    index_history: std::collections::VecDeque<f32>,
+    middle_history: std::collections::VecDeque<f32>,
+    index_was_pressed: bool,
+    middle_was_pressed: bool,
+    /// Set by the most recent `SensorReading` that crossed its threshold on
+    /// the rising edge; drained by `take_tap`.
+    pending_tap: Option<(Finger, f32)>,
+}
+
+impl Default for SensorPanel {
+    fn default() -> Self {
+        Self {
+            index_pressure: 0.0,
+            middle_pressure: 0.0,
+            index_raw: 0.0,
+            middle_raw: 0.0,
+            index_threshold: 0.3,
+            middle_threshold: 0.3,
+            index_history: std::collections::VecDeque::new(),
+            middle_history: std::collections::VecDeque::new(),
+            index_was_pressed: false,
+            middle_was_pressed: false,
+            pending_tap: None,
+        }
+    }
+}
+
+impl SensorPanel {
+    pub fn pressure(&self, finger: Finger) -> f32 {
+        match finger {
+            Finger::Index => self.index_pressure,
+            Finger::Middle => self.middle_pressure,
+        }
+    }
+
+    /// The last raw (pre-calibration) reading for `finger`, for the
+    /// calibration editor's live overlay.
+    pub fn raw(&self, finger: Finger) -> f32 {
+        match finger {
+            Finger::Index => self.index_raw,
+            Finger::Middle => self.middle_raw,
+        }
+    }
+
+    /// Drains the most recently detected tap, if any.
+    pub fn take_tap(&mut self) -> Option<(Finger, f32)> {
+        self.pending_tap.take()
+    }
+}
+
+impl SensorPanel {
+    /// Just the live pressure bars, no history plot — for basic mode, where
+    /// the whole point is to drop the `egui_plot` graph.
+    pub fn draw_compact(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Index finger:");
+            ui.add(egui::ProgressBar::new(self.index_pressure).text(format!("{:.1}%", self.index_pressure * 100.0)));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Middle finger:");
+            ui.add(egui::ProgressBar::new(self.middle_pressure).text(format!("{:.1}%", self.middle_pressure * 100.0)));
+        });
+    }
+}
+
+impl Component for SensorPanel {
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        let UIEvent::SensorReading { finger, raw, pressure, tap_threshold, history_capacity } = event else {
+            return false;
+        };
+
+        let (current, raw_field, threshold_field, history, was_pressed) = match finger {
+            Finger::Index => (
+                &mut self.index_pressure,
+                &mut self.index_raw,
+                &mut self.index_threshold,
+                &mut self.index_history,
+                &mut self.index_was_pressed,
+            ),
+            Finger::Middle => (
+                &mut self.middle_pressure,
+                &mut self.middle_raw,
+                &mut self.middle_threshold,
+                &mut self.middle_history,
+                &mut self.middle_was_pressed,
+            ),
+        };
+
+        *current = *pressure;
+        *raw_field = *raw;
+        *threshold_field = *tap_threshold;
+        history.push_back(*pressure);
+        while history.len() > *history_capacity {
+            history.pop_front();
+        }
+
+        let pressed = *pressure > *tap_threshold;
+        if pressed && !*was_pressed {
+            self.pending_tap = Some((*finger, *pressure));
+        }
+        *was_pressed = pressed;
+
+        true
+    }
+
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Sensor Data");
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("Index finger:");
+            ui.add(egui::ProgressBar::new(self.index_pressure).text(format!("{:.1}%", self.index_pressure * 100.0)));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Middle finger:");
+            ui.add(egui::ProgressBar::new(self.middle_pressure).text(format!("{:.1}%", self.middle_pressure * 100.0)));
+        });
+
+        ui.separator();
+        ui.label("Pressure History");
+
+        let index_points: egui_plot::PlotPoints =
+            self.index_history.iter().enumerate().map(|(i, &p)| [i as f64, p as f64]).collect();
+        let middle_points: egui_plot::PlotPoints =
+            self.middle_history.iter().enumerate().map(|(i, &p)| [i as f64, p as f64]).collect();
+
+        egui_plot::Plot::new("pressure_plot")
+            .height(200.0)
+            .include_y(0.0)
+            .include_y(1.0)
+            .show(ui, |plot_ui| {
+                plot_ui.line(egui_plot::Line::new(index_points).name("Index").color(egui::Color32::LIGHT_BLUE));
+                plot_ui.line(egui_plot::Line::new(middle_points).name("Middle").color(egui::Color32::LIGHT_GREEN));
+                plot_ui.hline(
+                    egui_plot::HLine::new(self.index_threshold as f64)
+                        .name("Index threshold")
+                        .color(egui::Color32::LIGHT_BLUE)
+                        .style(egui_plot::LineStyle::dashed_dense()),
+                );
+                plot_ui.hline(
+                    egui_plot::HLine::new(self.middle_threshold as f64)
+                        .name("Middle threshold")
+                        .color(egui::Color32::LIGHT_GREEN)
+                        .style(egui_plot::LineStyle::dashed_dense()),
+                );
+            });
+    }
+}
+
+/// Whether the user clicked the Start/End button this frame, for
+/// `TactilisApp` to act on (it owns the actual session lifecycle —
+/// forwarding to Unity, persisting history — so `SessionPanel` only raises
+/// the request, same way a clicked button always has to escape its widget).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingSessionAction {
+    Start,
+    End,
+}
+
+/// Session start/end control and the "active" indicator.
+pub struct SessionPanel {
+    active: bool,
+    session_id: Option<String>,
+    pending_action: Option<PendingSessionAction>,
+}
+
+impl Default for SessionPanel {
+    fn default() -> Self {
+        Self { active: false, session_id: None, pending_action: None }
+    }
+}
+
+impl SessionPanel {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// The current session id, if one is active — e.g. for resending
+    /// `SessionStart` to a Unity client that just reconnected mid-session.
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    /// Takes and clears the current session id, for `TactilisApp` to stamp
+    /// onto the `CompletedSession` record when a session ends.
+    pub fn take_session_id(&mut self) -> Option<String> {
+        self.session_id.take()
+    }
+
+    pub fn take_pending_action(&mut self) -> Option<PendingSessionAction> {
+        self.pending_action.take()
+    }
+}
+
+impl Component for SessionPanel {
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        match event {
+            UIEvent::SessionControl(SessionControl::Start(session_id)) => {
+                self.active = true;
+                self.session_id = Some(session_id.clone());
+                true
+            }
+            UIEvent::SessionControl(SessionControl::End) => {
+                self.active = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Session");
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            if self.active {
+                if ui.button("â¹ End Session").clicked() {
+                    self.pending_action = Some(PendingSessionAction::End);
+                }
+                ui.colored_label(egui::Color32::GREEN, "Session Active");
+            } else if ui.button("â–¶ Start Session").clicked() {
+                self.pending_action = Some(PendingSessionAction::Start);
+            }
+        });
+    }
+}
+
+/// Session statistics and the recent-taps log. Tracks its own `active` flag
+/// (from `SessionControl`) so it knows whether a `TargetHit` should count.
+pub struct StatsPanel {
+    stats: SessionStats,
+    tap_log: Vec<TapEvent>,
+    active: bool,
+}
+
+impl Default for StatsPanel {
+    fn default() -> Self {
+        Self { stats: SessionStats::default(), tap_log: Vec::new(), active: false }
+    }
+}
+
+impl StatsPanel {
+    pub fn stats(&self) -> &SessionStats {
+        &self.stats
+    }
+
+    pub fn tap_log(&self) -> &[TapEvent] {
+        &self.tap_log
+    }
+
+    /// Just the core `SessionStats` numbers, no recent-taps scroll area —
+    /// for basic mode's single compact column.
+    pub fn draw_compact(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Statistics");
+        egui::Grid::new("stats_grid_compact").show(ui, |ui| {
+            ui.label("Total taps:");
+            ui.label(self.stats.total_taps.to_string());
+            ui.end_row();
+
+            ui.label("Successful taps:");
+            ui.label(self.stats.successful_taps.to_string());
+            ui.end_row();
+
+            ui.label("Avg reaction time:");
+            ui.label(format!("{:.0} ms", self.stats.average_reaction_time_ms));
+            ui.end_row();
+
+            ui.label("Avg accuracy:");
+            ui.label(format!("{:.1}%", self.stats.average_accuracy * 100.0));
+            ui.end_row();
+        });
+    }
+}
+
+impl Component for StatsPanel {
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        match event {
+            UIEvent::SessionControl(SessionControl::Start(_)) => {
+                self.stats = SessionStats::default();
+                self.tap_log.clear();
+                self.active = true;
+                true
+            }
+            UIEvent::SessionControl(SessionControl::End) => {
+                self.active = false;
+                true
+            }
+            UIEvent::TapDetected(tap) => {
+                self.tap_log.push(tap.clone());
+                if self.tap_log.len() > 100 {
+                    self.tap_log.remove(0);
+                }
+                true
+            }
+            UIEvent::UnityMessage(UnityToCoreMessage::TargetHit { .. }) if self.active => {
+                self.stats.total_taps += 1;
+                self.stats.successful_taps += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Statistics");
+        egui::Grid::new("stats_grid").show(ui, |ui| {
+            ui.label("Total taps:");
+            ui.label(self.stats.total_taps.to_string());
+            ui.end_row();
+
+            ui.label("Successful taps:");
+            ui.label(self.stats.successful_taps.to_string());
+            ui.end_row();
+
+            ui.label("Avg reaction time:");
+            ui.label(format!("{:.0} ms", self.stats.average_reaction_time_ms));
+            ui.end_row();
+
+            ui.label("Avg accuracy:");
+            ui.label(format!("{:.1}%", self.stats.average_accuracy * 100.0));
+            ui.end_row();
+        });
+
+        ui.separator();
+
+        ui.heading("Recent Taps");
+        egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
+            for tap in self.tap_log.iter().rev().take(10) {
+                ui.horizontal(|ui| {
+                    let finger_str = match tap.finger {
+                        Finger::Index => "Index",
+                        Finger::Middle => "Middle",
+                    };
+                    ui.label(tap.timestamp.format("%H:%M:%S").to_string());
+                    ui.label(finger_str);
+                    ui.label(format!("{:.0}%", tap.pressure_peak * 100.0));
+                });
+            }
+        });
+    }
+}
+
+/// Ring-buffer capacity for `InspectorPanel`'s frame log.
+const INSPECTOR_CAPACITY: usize = 500;
+
+/// Which leg of the wire an `InspectorEntry` was recorded on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectorDirection {
+    SensorIn,
+    UnityOut,
+    UnityIn,
+}
+
+#[derive(Debug, Clone)]
+pub struct InspectorEntry {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub direction: InspectorDirection,
+    pub finger: Option<Finger>,
+    pub is_error: bool,
+    pub detail: String,
+}
+
+/// Live wire inspector: a bounded ring buffer of every sensor reading (raw
+/// value and its calibrated pressure, or the parse error if the frame was
+/// malformed) and every Unity message in either direction, each timestamped.
+/// `TactilisApp` calls `record` directly at the point each frame is
+/// produced or consumed — unlike the other panels, it doesn't react to
+/// `UIEvent`, since nothing in that enum carries outgoing Unity JSON or a
+/// parse failure's raw line. Pausing freezes the feed so a developer can
+/// inspect a malformed frame without stopping the session; the direction/
+/// finger/errors-only filters cut through a busy multi-finger stream.
+pub struct InspectorPanel {
+    entries: std::collections::VecDeque<InspectorEntry>,
+    paused: bool,
+    filter_direction: Option<InspectorDirection>,
+    filter_finger: Option<Finger>,
+    errors_only: bool,
+}
+
+impl Default for InspectorPanel {
+    fn default() -> Self {
+        Self {
+            entries: std::collections::VecDeque::new(),
+            paused: false,
+            filter_direction: None,
+            filter_finger: None,
+            errors_only: false,
+        }
+    }
+}
+
+impl InspectorPanel {
+    /// Records one entry, unless the feed is paused. Oldest entries are
+    /// dropped once the ring buffer is full.
+    pub fn record(&mut self, direction: InspectorDirection, finger: Option<Finger>, is_error: bool, detail: String) {
+        if self.paused {
+            return;
+        }
+        self.entries.push_back(InspectorEntry { timestamp: Utc::now(), direction, finger, is_error, detail });
+        while self.entries.len() > INSPECTOR_CAPACITY {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn draw(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.paused, "Pause");
+            ui.checkbox(&mut self.errors_only, "Errors only");
+            egui::ComboBox::from_label("Direction")
+                .selected_text(match self.filter_direction {
+                    None => "All",
+                    Some(InspectorDirection::SensorIn) => "Sensor in",
+                    Some(InspectorDirection::UnityOut) => "Unity out",
+                    Some(InspectorDirection::UnityIn) => "Unity in",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.filter_direction, None, "All");
+                    ui.selectable_value(&mut self.filter_direction, Some(InspectorDirection::SensorIn), "Sensor in");
+                    ui.selectable_value(&mut self.filter_direction, Some(InspectorDirection::UnityOut), "Unity out");
+                    ui.selectable_value(&mut self.filter_direction, Some(InspectorDirection::UnityIn), "Unity in");
+                });
+            egui::ComboBox::from_label("Finger")
+                .selected_text(match self.filter_finger {
+                    None => "All",
+                    Some(Finger::Index) => "Index",
+                    Some(Finger::Middle) => "Middle",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.filter_finger, None, "All");
+                    ui.selectable_value(&mut self.filter_finger, Some(Finger::Index), "Index");
+                    ui.selectable_value(&mut self.filter_finger, Some(Finger::Middle), "Middle");
+                });
+            if ui.button("Clear").clicked() {
+                self.entries.clear();
+            }
+        });
+        ui.separator();
+
+        egui::ScrollArea::vertical().max_height(320.0).stick_to_bottom(true).show(ui, |ui| {
+            for entry in self.entries.iter().filter(|e| {
+                (!self.errors_only || e.is_error)
+                    && self.filter_direction.map_or(true, |d| d == e.direction)
+                    && self.filter_finger.map_or(true, |f| e.finger == Some(f))
+            }) {
+                let dir_label = match entry.direction {
+                    InspectorDirection::SensorIn => "sensor",
+                    InspectorDirection::UnityOut => "unity->",
+                    InspectorDirection::UnityIn => "unity<-",
+                };
+                let color = if entry.is_error { egui::Color32::LIGHT_RED } else { ui.visuals().text_color() };
+                ui.colored_label(
+                    color,
+                    format!("{} [{}] {}", entry.timestamp.format("%H:%M:%S%.3f"), dir_label, entry.detail),
+                );
+            }
+        });
+    }
+}
+
+/// Scrolling status log.
+#[derive(Default)]
+pub struct LogPanel {
+    messages: std::collections::VecDeque<String>,
+}
+
+impl Component for LogPanel {
+    fn handle_event(&mut self, event: &UIEvent) -> bool {
+        let UIEvent::StatusMessage(message) = event else { return false };
+        self.messages.push_front(message.clone());
+        if self.messages.len() > 50 {
+            self.messages.pop_back();
+        }
+        true
+    }
+
+    fn draw(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Status Log");
+        egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
+            for msg in &self.messages {
+                ui.label(msg);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sensor_panel_detects_tap_on_rising_edge() {
+        let mut panel = SensorPanel::default();
+        let reading = |pressure: f32| UIEvent::SensorReading {
+            finger: Finger::Index,
+            raw: pressure,
+            pressure,
+            tap_threshold: 0.3,
+            history_capacity: 10,
+        };
+
+        panel.handle_event(&reading(0.1));
+        assert_eq!(panel.take_tap(), None);
+
+        panel.handle_event(&reading(0.5));
+        assert_eq!(panel.take_tap(), Some((Finger::Index, 0.5)));
+
+        // Staying above threshold shouldn't re-fire until it drops and rises again.
+        panel.handle_event(&reading(0.6));
+        assert_eq!(panel.take_tap(), None);
+
+        panel.handle_event(&reading(0.1));
+        panel.handle_event(&reading(0.4));
+        assert_eq!(panel.take_tap(), Some((Finger::Index, 0.4)));
+    }
+
+    #[test]
+    fn stats_panel_only_counts_target_hits_while_active() {
+        let mut panel = StatsPanel::default();
+        let hit = UIEvent::UnityMessage(UnityToCoreMessage::TargetHit {
+            target_id: 1,
+            hit_position: [0.0, 0.0, 0.0],
+            timestamp: chrono::Utc::now(),
+        });
+
+        panel.handle_event(&hit);
+        assert_eq!(panel.stats().total_taps, 0);
+
+        panel.handle_event(&UIEvent::SessionControl(SessionControl::Start("s1".to_string())));
+        panel.handle_event(&hit);
+        assert_eq!(panel.stats().total_taps, 1);
+
+        panel.handle_event(&UIEvent::SessionControl(SessionControl::End));
+        panel.handle_event(&hit);
+        assert_eq!(panel.stats().total_taps, 1);
+    }
+}
@@ -0,0 +1,161 @@
+//! User-editable configuration (`config.yaml`), loaded at startup and
+//! written back whenever the settings panel changes something.
+//!
+//! Kept separate from `state.rs`: this is the stuff a researcher tunes by
+//! hand (ports, thresholds, UI prefs), while state is accumulated session
+//! history the app writes on its own.
+//!
+//! Versioned with a `format_version` field, same as `state.rs`: a shape
+//! change that a plain `#[serde(default)]` can't bridge (a rename, a field
+//! that stops being optional) gets a bump here and a branch in `migrate`,
+//! instead of silently resetting a researcher's calibration and thresholds
+//! back to defaults the next time `config.yaml` is loaded.
+
+use crate::calibration::CalibrationCurve;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+/// Bump whenever `AppConfig`'s shape changes in a way serde's own field
+/// defaults can't bridge, and add a branch to `migrate` that rewrites the
+/// previous version's YAML mapping into the current shape.
+const CURRENT_CONFIG_VERSION: u32 = 1;
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+}
+
+/// Per-finger pressure threshold (0.0 - 1.0) above which a tap is detected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TapThresholds {
+    pub index: f32,
+    pub middle: f32,
+}
+
+impl Default for TapThresholds {
+    fn default() -> Self {
+        Self { index: 0.3, middle: 0.3 }
+    }
+}
+
+/// UI preferences, kept separate from connection settings so the settings
+/// panel can group them independently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UiPreferences {
+    /// Number of samples kept in the pressure history graph.
+    pub sensor_history_size: usize,
+    /// Collapse to a single compact column (live pressure bars, session
+    /// control, core stats) with no history plot — for running on a small
+    /// secondary monitor during a therapy session.
+    #[serde(default)]
+    pub basic_mode: bool,
+}
+
+impl Default for UiPreferences {
+    fn default() -> Self {
+        Self { sensor_history_size: 200, basic_mode: false }
+    }
+}
+
+/// Top-level shape of `config.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    #[serde(default)]
+    pub format_version: u32,
+    /// Id of the `SensorBackendKind` to use, offered as the default
+    /// selection on the next launch. See `SensorBackendKind::{id, from_id}`.
+    pub sensor_backend: String,
+    /// Last device the user connected to. Meaning depends on
+    /// `sensor_backend`: a serial port, a BLE device name, a replay file...
+    pub last_sensor_device: Option<String>,
+    pub baud_rate: u32,
+    /// Bind port for the UDP sensor backend.
+    pub udp_sensor_port: u16,
+    pub unity_host: String,
+    pub unity_port: u16,
+    /// Port for the optional low-latency UDP mirror of the Unity link. `None`
+    /// (the default) leaves it disabled; see `unity::UdpTransportConfig`.
+    #[serde(default)]
+    pub unity_udp_port: Option<u16>,
+    pub tap_thresholds: TapThresholds,
+    /// Per-finger raw-reading -> pressure calibration curves. See
+    /// `calibration::CalibrationCurve`.
+    pub index_calibration: CalibrationCurve,
+    pub middle_calibration: CalibrationCurve,
+    pub ui: UiPreferences,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            format_version: CURRENT_CONFIG_VERSION,
+            sensor_backend: "arduino_serial".to_string(),
+            last_sensor_device: None,
+            baud_rate: 115200,
+            udp_sensor_port: 9001,
+            unity_host: "127.0.0.1".to_string(),
+            unity_port: 8765,
+            unity_udp_port: None,
+            tap_thresholds: TapThresholds::default(),
+            index_calibration: CalibrationCurve::default(),
+            middle_calibration: CalibrationCurve::default(),
+            ui: UiPreferences::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Loads `path`, migrating forward if its `format_version` is older than
+    /// `CURRENT_CONFIG_VERSION`. A missing file just yields a fresh default.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        let raw: serde_yaml::Value = serde_yaml::from_str(&contents)?;
+        Ok(migrate(raw)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let yaml = serde_yaml::to_string(self)?;
+        std::fs::write(path, yaml)?;
+        Ok(())
+    }
+}
+
+/// Upgrades a raw `config.yaml` mapping to `CURRENT_CONFIG_VERSION` before
+/// deserializing it into `AppConfig`, so a rename or a field that stops
+/// being optional doesn't just fail to parse and fall back to
+/// `AppConfig::default()`. Each past version gets one branch here, same as
+/// `state::migrate`.
+fn migrate(mut raw: serde_yaml::Value) -> Result<AppConfig, serde_yaml::Error> {
+    let format_version = raw.get("format_version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+
+    if format_version < 1 {
+        // Pre-chunk2-2 files (`last_serial_port: Option<String>`, no
+        // `sensor_backend`/`udp_sensor_port`/calibration curves). Everything
+        // else already has the same shape, so only rewrite what changed.
+        if let Some(mapping) = raw.as_mapping_mut() {
+            if let Some(last_serial_port) = mapping.remove("last_serial_port") {
+                mapping.insert("last_sensor_device".into(), last_serial_port);
+            }
+            mapping
+                .entry("sensor_backend".into())
+                .or_insert_with(|| "arduino_serial".into());
+            mapping.entry("udp_sensor_port".into()).or_insert_with(|| 9001.into());
+            mapping
+                .entry("index_calibration".into())
+                .or_insert_with(|| serde_yaml::to_value(CalibrationCurve::default()).unwrap());
+            mapping
+                .entry("middle_calibration".into())
+                .or_insert_with(|| serde_yaml::to_value(CalibrationCurve::default()).unwrap());
+            mapping.insert("format_version".into(), CURRENT_CONFIG_VERSION.into());
+        }
+    }
+
+    serde_yaml::from_value(raw)
+}
@@ -1,6 +1,15 @@
 mod app;
 mod arduino;
+mod backoff;
+mod calibration;
+mod component;
+mod config;
 mod protocol;
+mod sensor;
+mod state;
+mod supervisor;
+mod telemetry;
+mod toast;
 mod unity;
 
 use app::TactilisApp;
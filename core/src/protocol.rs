@@ -4,6 +4,7 @@
 //! - Arduino → Core: Sensor readings
 //! - Core → Unity: Visual cue triggers and session data
 //! - Unity → Core: Game events and user interactions
+//! - Core → MQTT: Sensor/tap/metrics/session telemetry, mirrored for external dashboards
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -31,6 +32,12 @@ pub struct TapEvent {
     pub pressure_peak: f32,
     pub duration_ms: u32,
     pub timestamp: DateTime<Utc>,
+    /// Microseconds elapsed since a shared `Instant` origin (app startup),
+    /// rather than wall-clock `timestamp`. Two taps landing in the same
+    /// sensor poll cycle can carry the same millisecond-resolution
+    /// `timestamp`; this stays strictly increasing so a `TapBatch`
+    /// consumer can recover intra-cycle ordering.
+    pub monotonic_us: u64,
 }
 
 /// Target button in the AR environment.
@@ -76,6 +83,49 @@ pub enum ArduinoMessage {
     Ready { firmware_version: String },
     /// Error from Arduino
     Error { message: String },
+    /// Device capabilities, sent once after `Ready` so Core knows what it's
+    /// actually talking to instead of assuming the original board's fixed
+    /// 10-bit ADC and two fingers.
+    Descriptor {
+        firmware_version: String,
+        num_fingers: u8,
+        /// Maximum raw ADC reading the device's sensors report, used to
+        /// normalize `SensorReading::pressure` instead of a hardcoded range.
+        adc_max: u16,
+    },
+}
+
+// ============================================================================
+// Messages: Core → Arduino (over USB Serial / BLE)
+// ============================================================================
+
+/// Messages sent from Core to Arduino, closing the biofeedback loop — e.g. so
+/// an AR target activation can simultaneously light or buzz the corresponding
+/// finger sensor rather than relying on visuals alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoreToArduinoMessage {
+    /// Turn a finger's indicator LED on or off.
+    SetLed { finger: Finger, on: bool },
+    /// Trigger the vibration motor for a finger.
+    Buzz {
+        finger: Finger,
+        intensity: f32, // 0.0 - 1.0
+        duration_ms: u32,
+    },
+    /// Ask the board to recalibrate its sensor baseline.
+    Calibrate,
+    /// Pushes a runtime feature report to the device: a new tap-detection
+    /// threshold, sample rate, and per-finger enable flags. Distinct from
+    /// `AppConfig::tap_thresholds`, which only governs Core's own
+    /// rising-edge detection — this reconfigures the device itself, e.g. so
+    /// a disabled finger's sensor stops being polled.
+    SetFeatureReport {
+        pressure_threshold: f32,
+        sample_rate_hz: u32,
+        index_enabled: bool,
+        middle_enabled: bool,
+    },
 }
 
 // ============================================================================
@@ -86,8 +136,15 @@ pub enum ArduinoMessage {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CoreToUnityMessage {
-    /// Notify Unity of a detected tap
-    TapDetected(TapEvent),
+    /// Every tap detected within one sensor poll cycle, in order, tagged
+    /// with a sequence number that increases by one per batch (not per
+    /// tap) so a client can tell a dropped/reordered batch from an empty
+    /// one. Replaces sending one `TapDetected`-style message per tap, which
+    /// cost a WebSocket frame per threshold crossing and gave no way to
+    /// tell whether two same-cycle taps (e.g. index and middle firing
+    /// together) arrived in the order they actually happened versus the
+    /// order their frames happened to be delivered.
+    TapBatch { sequence: u64, taps: Vec<TapEvent> },
     /// Activate a target button in AR
     ActivateTarget { target_id: u32 },
     /// Deactivate a target
@@ -96,11 +153,6 @@ pub enum CoreToUnityMessage {
     SessionStart { session_id: String },
     /// End the current session
     SessionEnd { stats: SessionStats },
-    /// Current sensor state (for live visualization)
-    SensorState {
-        index_pressure: f32,
-        middle_pressure: f32,
-    },
 }
 
 /// Messages sent from Unity to Core.
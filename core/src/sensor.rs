@@ -0,0 +1,490 @@
+//! Pluggable sensor backends.
+//!
+//! `TactilisApp` used to be hardwired to `arduino::connect`/`ArduinoHandle`.
+//! This module lifts that behind a `SensorBackend` trait so the dashboard can
+//! drive non-Arduino tactile hardware (a BLE glove by name, a TCP-bridged
+//! glove, a bare UDP stream, or a recorded file for demos) without the UI or
+//! tap-detection code caring which one is live. Each backend lives behind
+//! its own Cargo feature (`arduino`, `bluetooth_glove`, `tcp_bridge`,
+//! `udp_sensor`, `replay_file`); a backend whose feature is off at build
+//! time still shows up in the picker, it just reports
+//! `SensorError::FeatureDisabled` instead of connecting.
+
+use crate::arduino::{self, ArduinoAutoHandle, ArduinoConfig, ArduinoHandle, ArduinoTransport};
+use crate::config::AppConfig;
+use crate::protocol::{ArduinoMessage, CoreToArduinoMessage};
+use std::io::Read;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+use thiserror::Error;
+
+pub use crate::arduino::ConnectionState;
+
+/// Directory `ReplayFileBackend::list_devices` scans for recordings.
+const REPLAY_DIR: &str = "replays";
+/// Delay between lines while replaying a recording.
+const REPLAY_INTERVAL: Duration = Duration::from_millis(20);
+/// Read/sleep timeout used to periodically recheck a worker's shutdown flag.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Error, Debug)]
+pub enum SensorError {
+    #[error(transparent)]
+    Arduino(#[from] arduino::ArduinoError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON parse error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("the \"{0}\" backend needs a device/file/name to connect to")]
+    DeviceRequired(&'static str),
+    #[error("the \"{0}\" backend was not compiled into this build")]
+    FeatureDisabled(&'static str),
+}
+
+/// Which sensor backend is driving the dashboard. Always fully enumerable so
+/// the picker shows every option regardless of which features were compiled
+/// in; `build_backend` is what actually gates availability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SensorBackendKind {
+    ArduinoSerial,
+    BluetoothGlove,
+    UdpSensor,
+    ReplayFile,
+    TcpBridge,
+}
+
+impl SensorBackendKind {
+    pub const ALL: [SensorBackendKind; 5] = [
+        SensorBackendKind::ArduinoSerial,
+        SensorBackendKind::BluetoothGlove,
+        SensorBackendKind::UdpSensor,
+        SensorBackendKind::ReplayFile,
+        SensorBackendKind::TcpBridge,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SensorBackendKind::ArduinoSerial => "Arduino (Serial)",
+            SensorBackendKind::BluetoothGlove => "Bluetooth Glove (BLE)",
+            SensorBackendKind::UdpSensor => "UDP Sensor",
+            SensorBackendKind::ReplayFile => "Replay File",
+            SensorBackendKind::TcpBridge => "TCP Bridge",
+        }
+    }
+
+    /// Stable id persisted in `config.yaml`.
+    pub fn id(self) -> &'static str {
+        match self {
+            SensorBackendKind::ArduinoSerial => "arduino_serial",
+            SensorBackendKind::BluetoothGlove => "bluetooth_glove",
+            SensorBackendKind::UdpSensor => "udp_sensor",
+            SensorBackendKind::ReplayFile => "replay_file",
+            SensorBackendKind::TcpBridge => "tcp_bridge",
+        }
+    }
+
+    /// Inverse of `id`; unrecognized or missing ids fall back to the
+    /// original default backend.
+    pub fn from_id(id: &str) -> Self {
+        match id {
+            "bluetooth_glove" => SensorBackendKind::BluetoothGlove,
+            "udp_sensor" => SensorBackendKind::UdpSensor,
+            "replay_file" => SensorBackendKind::ReplayFile,
+            "tcp_bridge" => SensorBackendKind::TcpBridge,
+            _ => SensorBackendKind::ArduinoSerial,
+        }
+    }
+}
+
+/// A source of tactile readings: enumerates devices it could connect to and
+/// opens a live `SensorConnection` to one of them.
+pub trait SensorBackend: Send {
+    /// Lists devices/endpoints this backend can connect to right now (serial
+    /// ports, recording files...). Backends that match by name or have a
+    /// single implicit endpoint (BLE, UDP) may return an empty list; the
+    /// device picker falls back to free text in that case.
+    fn list_devices(&self) -> Vec<String>;
+
+    /// Connects to `device` (meaning depends on the backend: a port name, a
+    /// BLE peripheral's advertised name, a replay file...). `None` is only
+    /// valid for backends that can discover a device on their own, namely
+    /// Arduino's scan/probe/reconnect state machine, or that ignore `device`
+    /// because they have a single implicit endpoint (UDP).
+    fn connect(&self, device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError>;
+}
+
+/// A live connection opened by a `SensorBackend`. Mirrors `ArduinoHandle`'s
+/// shape (drain readings, send commands, shut down cleanly) generalized
+/// across backends that aren't necessarily Arduino-shaped underneath.
+pub trait SensorConnection: Send {
+    /// Drains whatever `ArduinoMessage`s have arrived since the last poll.
+    fn try_recv(&mut self) -> Vec<ArduinoMessage>;
+    /// Drains connection-lifecycle transitions, if this backend reports any
+    /// (Arduino's auto-discovery does; most backends don't and return empty).
+    fn try_recv_state(&mut self) -> Vec<ConnectionState>;
+    /// Sends an outbound cue/command, if the backend supports one. A no-op
+    /// for read-only backends (UDP sensor, replay file).
+    fn send_command(&self, cmd: CoreToArduinoMessage);
+    /// Signals the connection to stop and waits for its worker to exit.
+    fn shutdown(self: Box<Self>) -> Result<(), SensorError>;
+}
+
+impl SensorConnection for ArduinoHandle {
+    fn try_recv(&mut self) -> Vec<ArduinoMessage> {
+        self.receiver.try_iter().collect()
+    }
+
+    fn try_recv_state(&mut self) -> Vec<ConnectionState> {
+        Vec::new()
+    }
+
+    fn send_command(&self, cmd: CoreToArduinoMessage) {
+        let _ = self.sender.send(cmd);
+    }
+
+    fn shutdown(self: Box<Self>) -> Result<(), SensorError> {
+        Ok(ArduinoHandle::shutdown(*self)?)
+    }
+}
+
+impl SensorConnection for ArduinoAutoHandle {
+    fn try_recv(&mut self) -> Vec<ArduinoMessage> {
+        self.receiver.try_iter().collect()
+    }
+
+    fn try_recv_state(&mut self) -> Vec<ConnectionState> {
+        self.state_receiver.try_iter().collect()
+    }
+
+    fn send_command(&self, cmd: CoreToArduinoMessage) {
+        let _ = self.sender.send(cmd);
+    }
+
+    fn shutdown(self: Box<Self>) -> Result<(), SensorError> {
+        ArduinoAutoHandle::shutdown(*self);
+        Ok(())
+    }
+}
+
+/// The original transport, now behind `SensorBackend`. A `device` of `None`
+/// (or empty) hands port selection off to `arduino::connect_auto`.
+struct ArduinoSerialBackend {
+    baud_rate: u32,
+}
+
+impl SensorBackend for ArduinoSerialBackend {
+    fn list_devices(&self) -> Vec<String> {
+        arduino::list_available_ports()
+    }
+
+    fn connect(&self, device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError> {
+        match device.filter(|d| !d.is_empty()) {
+            Some(port_name) => {
+                let handle = arduino::connect(ArduinoTransport::Serial(ArduinoConfig {
+                    port_name: port_name.to_string(),
+                    baud_rate: self.baud_rate,
+                }))?;
+                Ok(Box::new(handle))
+            }
+            None => Ok(Box::new(arduino::connect_auto(self.baud_rate))),
+        }
+    }
+}
+
+/// Wraps the existing BLE transport. Peripherals are matched by advertised
+/// name at connect time rather than enumerated up front, so `list_devices`
+/// is empty and the device field is always free text.
+struct BluetoothGloveBackend;
+
+impl SensorBackend for BluetoothGloveBackend {
+    fn list_devices(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn connect(&self, device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError> {
+        let device_name = device
+            .filter(|d| !d.is_empty())
+            .ok_or(SensorError::DeviceRequired("bluetooth_glove"))?
+            .to_string();
+        let handle = arduino::connect(ArduinoTransport::Ble { device_name })?;
+        Ok(Box::new(handle))
+    }
+}
+
+/// Wraps the TCP bridge transport, for a glove whose serial bytes are
+/// forwarded over the network (e.g. a Raspberry Pi sitting next to the
+/// hardware) rather than plugged directly into the machine running Core.
+/// `device` is a `host:port` address rather than an enumerable endpoint, so
+/// `list_devices` is empty, same as `BluetoothGloveBackend`. Reconnects on
+/// its own, like the serial transport's auto-discovery, since a bridge
+/// dropping and coming back shouldn't require the user to hit Connect again.
+struct TcpBridgeBackend;
+
+impl SensorBackend for TcpBridgeBackend {
+    fn list_devices(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn connect(&self, device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError> {
+        let addr_str = device
+            .filter(|d| !d.is_empty())
+            .ok_or(SensorError::DeviceRequired("tcp_bridge"))?;
+        let addr = addr_str
+            .parse()
+            .map_err(|_| SensorError::DeviceRequired("tcp_bridge"))?;
+        let handle = arduino::connect(ArduinoTransport::Tcp(arduino::TcpConfig { addr, reconnect: true }))?;
+        Ok(Box::new(handle))
+    }
+}
+
+/// A bare UDP socket receiving one JSON `ArduinoMessage` per datagram, for
+/// non-Arduino hardware (or a test harness) that can push readings without
+/// speaking the serial handshake. Read-only: `send_command` is a no-op.
+struct UdpSensorBackend {
+    port: u16,
+}
+
+struct UdpSensorConnection {
+    receiver: mpsc::Receiver<ArduinoMessage>,
+    shutdown: Arc<AtomicBool>,
+    thread: std::thread::JoinHandle<Result<(), SensorError>>,
+}
+
+impl SensorBackend for UdpSensorBackend {
+    fn list_devices(&self) -> Vec<String> {
+        vec![format!("0.0.0.0:{}", self.port)]
+    }
+
+    fn connect(&self, _device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError> {
+        let socket = std::net::UdpSocket::bind(("0.0.0.0", self.port))?;
+        socket.set_read_timeout(Some(SHUTDOWN_POLL_INTERVAL))?;
+
+        let (sender, receiver) = mpsc::channel();
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+
+        let thread = std::thread::spawn(move || {
+            let result = run_udp_read_loop(socket, &sender, &thread_shutdown);
+            tracing::info!("UDP sensor thread exiting");
+            result
+        });
+
+        Ok(Box::new(UdpSensorConnection { receiver, shutdown, thread }))
+    }
+}
+
+impl SensorConnection for UdpSensorConnection {
+    fn try_recv(&mut self) -> Vec<ArduinoMessage> {
+        self.receiver.try_iter().collect()
+    }
+
+    fn try_recv_state(&mut self) -> Vec<ConnectionState> {
+        Vec::new()
+    }
+
+    fn send_command(&self, _cmd: CoreToArduinoMessage) {
+        // Read-only backend: nothing to push to.
+    }
+
+    fn shutdown(self: Box<Self>) -> Result<(), SensorError> {
+        self.shutdown.store(true, Ordering::Relaxed);
+        self.thread.join().unwrap_or(Ok(()))
+    }
+}
+
+fn run_udp_read_loop(
+    socket: std::net::UdpSocket,
+    sender: &mpsc::Sender<ArduinoMessage>,
+    shutdown: &AtomicBool,
+) -> Result<(), SensorError> {
+    let mut buf = [0u8; 1024];
+    while !shutdown.load(Ordering::Relaxed) {
+        match socket.recv(&mut buf) {
+            Ok(n) => match serde_json::from_slice::<ArduinoMessage>(&buf[..n]) {
+                Ok(msg) => {
+                    if sender.send(msg).is_err() {
+                        return Ok(());
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to parse UDP sensor datagram: {}", e),
+            },
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut || e.kind() == std::io::ErrorKind::WouldBlock => {
+                continue;
+            }
+            Err(e) => {
+                tracing::error!("UDP sensor read error: {}", e);
+                return Err(e.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replays a recorded session (newline-delimited JSON `ArduinoMessage`s,
+/// under `REPLAY_DIR`) at a fixed cadence, looping forever, for demos and
+/// testing the rest of the dashboard without physical hardware.
+struct ReplayFileBackend;
+
+struct ReplayFileConnection {
+    receiver: mpsc::Receiver<ArduinoMessage>,
+    shutdown: Arc<AtomicBool>,
+    thread: std::thread::JoinHandle<Result<(), SensorError>>,
+}
+
+impl SensorBackend for ReplayFileBackend {
+    fn list_devices(&self) -> Vec<String> {
+        std::fs::read_dir(REPLAY_DIR)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    fn connect(&self, device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError> {
+        let file_name = device
+            .filter(|d| !d.is_empty())
+            .ok_or(SensorError::DeviceRequired("replay_file"))?;
+        let path = Path::new(REPLAY_DIR).join(file_name);
+
+        let mut contents = String::new();
+        std::fs::File::open(&path)?.read_to_string(&mut contents)?;
+        let lines: Vec<ArduinoMessage> = contents
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+
+        let (sender, receiver) = mpsc::channel();
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+
+        let thread = std::thread::spawn(move || {
+            let result = run_replay_loop(&lines, &sender, &thread_shutdown);
+            tracing::info!("Replay file thread exiting");
+            result
+        });
+
+        Ok(Box::new(ReplayFileConnection { receiver, shutdown, thread }))
+    }
+}
+
+impl SensorConnection for ReplayFileConnection {
+    fn try_recv(&mut self) -> Vec<ArduinoMessage> {
+        self.receiver.try_iter().collect()
+    }
+
+    fn try_recv_state(&mut self) -> Vec<ConnectionState> {
+        Vec::new()
+    }
+
+    fn send_command(&self, _cmd: CoreToArduinoMessage) {
+        // Read-only backend: nothing to push to.
+    }
+
+    fn shutdown(self: Box<Self>) -> Result<(), SensorError> {
+        self.shutdown.store(true, Ordering::Relaxed);
+        self.thread.join().unwrap_or(Ok(()))
+    }
+}
+
+fn run_replay_loop(
+    lines: &[ArduinoMessage],
+    sender: &mpsc::Sender<ArduinoMessage>,
+    shutdown: &AtomicBool,
+) -> Result<(), SensorError> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    while !shutdown.load(Ordering::Relaxed) {
+        for msg in lines {
+            if shutdown.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+            if sender.send(msg.clone()).is_err() {
+                return Ok(());
+            }
+            std::thread::sleep(REPLAY_INTERVAL);
+        }
+    }
+    Ok(())
+}
+
+/// A backend whose Cargo feature wasn't compiled in. Kept selectable in the
+/// picker so the UI doesn't need to know which features a given build has;
+/// connecting to it just reports why it can't.
+struct UnavailableBackend {
+    feature: &'static str,
+}
+
+impl SensorBackend for UnavailableBackend {
+    fn list_devices(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn connect(&self, _device: Option<&str>) -> Result<Box<dyn SensorConnection>, SensorError> {
+        Err(SensorError::FeatureDisabled(self.feature))
+    }
+}
+
+/// Builds the backend for `kind`, reading whatever bits of `config` it
+/// needs (baud rate, UDP port...). Falls back to `UnavailableBackend` when
+/// the matching Cargo feature is disabled.
+pub fn build_backend(kind: SensorBackendKind, config: &AppConfig) -> Box<dyn SensorBackend> {
+    match kind {
+        SensorBackendKind::ArduinoSerial => {
+            #[cfg(feature = "arduino")]
+            {
+                Box::new(ArduinoSerialBackend { baud_rate: config.baud_rate })
+            }
+            #[cfg(not(feature = "arduino"))]
+            {
+                let _ = config;
+                Box::new(UnavailableBackend { feature: "arduino" })
+            }
+        }
+        SensorBackendKind::BluetoothGlove => {
+            #[cfg(feature = "bluetooth_glove")]
+            {
+                Box::new(BluetoothGloveBackend)
+            }
+            #[cfg(not(feature = "bluetooth_glove"))]
+            {
+                Box::new(UnavailableBackend { feature: "bluetooth_glove" })
+            }
+        }
+        SensorBackendKind::UdpSensor => {
+            #[cfg(feature = "udp_sensor")]
+            {
+                Box::new(UdpSensorBackend { port: config.udp_sensor_port })
+            }
+            #[cfg(not(feature = "udp_sensor"))]
+            {
+                let _ = config;
+                Box::new(UnavailableBackend { feature: "udp_sensor" })
+            }
+        }
+        SensorBackendKind::ReplayFile => {
+            #[cfg(feature = "replay_file")]
+            {
+                Box::new(ReplayFileBackend)
+            }
+            #[cfg(not(feature = "replay_file"))]
+            {
+                Box::new(UnavailableBackend { feature: "replay_file" })
+            }
+        }
+        SensorBackendKind::TcpBridge => {
+            #[cfg(feature = "tcp_bridge")]
+            {
+                Box::new(TcpBridgeBackend)
+            }
+            #[cfg(not(feature = "tcp_bridge"))]
+            {
+                Box::new(UnavailableBackend { feature: "tcp_bridge" })
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Persisted session history (`state.json`).
+//!
+//! Versioned with a `format_version` field so that a future shape change can
+//! migrate an old file forward in `migrate` instead of failing to deserialize
+//! it (and losing a researcher's session history in the process).
+
+use crate::protocol::{SessionStats, TapEvent};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+/// Bump whenever `PersistedState`'s shape changes, and add a branch to
+/// `migrate` that upgrades files written under the previous version.
+const CURRENT_FORMAT_VERSION: u32 = 1;
+
+#[derive(Error, Debug)]
+pub enum StateError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// One completed session's outcome, appended to `PersistedState::sessions`
+/// when `end_session` runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletedSession {
+    pub session_id: String,
+    pub ended_at: DateTime<Utc>,
+    pub stats: SessionStats,
+    pub taps: Vec<TapEvent>,
+}
+
+/// Top-level shape of `state.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedState {
+    pub format_version: u32,
+    pub sessions: Vec<CompletedSession>,
+}
+
+impl Default for PersistedState {
+    fn default() -> Self {
+        Self {
+            format_version: CURRENT_FORMAT_VERSION,
+            sessions: Vec::new(),
+        }
+    }
+}
+
+impl PersistedState {
+    /// Loads `path`, migrating forward if its `format_version` is older than
+    /// `CURRENT_FORMAT_VERSION`. A missing file just yields a fresh default.
+    pub fn load(path: &Path) -> Result<Self, StateError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let bytes = std::fs::read(path)?;
+        let mut state: Self = serde_json::from_slice(&bytes)?;
+        migrate(&mut state);
+        Ok(state)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), StateError> {
+        let json = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Upgrades `state` in place to `CURRENT_FORMAT_VERSION`. Each past version
+/// gets one match arm here instead of ad-hoc conversions scattered through
+/// `load`, so the migration path stays linear and auditable as the format grows.
+fn migrate(state: &mut PersistedState) {
+    match state.format_version {
+        CURRENT_FORMAT_VERSION => {}
+        0 => {
+            // Pre-versioning files had the same shape; just stamp the version.
+            state.format_version = CURRENT_FORMAT_VERSION;
+        }
+        other => {
+            tracing::warn!("Unknown state.json format_version {}, loading as-is", other);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Connection lifecycle supervisor, shared by the sensor and Unity links.
+//!
+//! Before this, each link surfaced its own bespoke notion of "connected":
+//! the sensor side has `sensor::ConnectionState` (from Arduino's own
+//! scan/probe/reconnect manager), while Unity was just a bare `bool`. Neither
+//! gave the UI a place to hang "do something once, on the transition" logic
+//! (resend `SessionStart` to a freshly reconnected Unity client, flush
+//! messages queued while it was gone). `ConnectionSupervisor` normalizes both
+//! into one `Disconnected -> Connecting -> Online -> Lost` state machine and
+//! hands back a `bool` on `set_online`/`set_lost` that's `true` exactly once,
+//! on the transition, so callers can fire an `on_online`/`on_offline` hook
+//! without hand-rolling the "did this just change" check themselves.
+//!
+//! It only tracks state and a backoff timer — it doesn't own the link or
+//! know how to reconnect it. Callers drive it from their own poll loop and
+//! check `due_for_retry` to know when to attempt a reconnect of their own.
+
+use crate::backoff::reconnect_backoff;
+use std::time::{Duration, Instant};
+
+/// Lifecycle of one supervised link.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinkState {
+    /// Never connected, or deliberately torn down (e.g. the user hit
+    /// "Disconnect") — not something to keep retrying.
+    Disconnected,
+    /// A connect attempt is in flight, or the link is listening/scanning for
+    /// its first peer.
+    Connecting,
+    Online,
+    /// Dropped after being `Online`, or failed to come up at all. Retrying
+    /// on the backoff schedule below.
+    Lost { attempt: u32, retry_in: Duration },
+}
+
+/// Tracks one link's lifecycle and when it's next due a reconnect attempt.
+pub struct ConnectionSupervisor {
+    state: LinkState,
+    next_retry_at: Option<Instant>,
+}
+
+impl Default for ConnectionSupervisor {
+    fn default() -> Self {
+        Self {
+            state: LinkState::Disconnected,
+            next_retry_at: None,
+        }
+    }
+}
+
+impl ConnectionSupervisor {
+    pub fn state(&self) -> LinkState {
+        self.state
+    }
+
+    /// A connect attempt started, or the link is waiting for its first peer.
+    pub fn set_connecting(&mut self) {
+        self.state = LinkState::Connecting;
+        self.next_retry_at = None;
+    }
+
+    /// The link is confirmed up. Returns `true` the first time (i.e. when
+    /// `on_online` should fire).
+    pub fn set_online(&mut self) -> bool {
+        let became_online = self.state != LinkState::Online;
+        self.state = LinkState::Online;
+        self.next_retry_at = None;
+        became_online
+    }
+
+    /// The link dropped. Bumps the retry counter if already `Lost`, starts
+    /// it at 1 otherwise. Returns `true` only when this is a fresh loss
+    /// (i.e. the previous state was `Online`) — that's the moment `on_offline`
+    /// should fire, as opposed to a still-`Connecting` link failing another
+    /// probe.
+    pub fn set_lost(&mut self) -> bool {
+        let was_online = self.state == LinkState::Online;
+        let attempt = match self.state {
+            LinkState::Lost { attempt, .. } => attempt + 1,
+            _ => 1,
+        };
+        let retry_in = reconnect_backoff(attempt);
+        self.state = LinkState::Lost { attempt, retry_in };
+        self.next_retry_at = Some(Instant::now() + retry_in);
+        was_online
+    }
+
+    /// Explicit, user-initiated teardown — resets to `Disconnected` rather
+    /// than `Lost`, so nothing keeps trying to reconnect behind the user's back.
+    pub fn set_disconnected(&mut self) {
+        self.state = LinkState::Disconnected;
+        self.next_retry_at = None;
+    }
+
+    /// True once per backoff window, when it's time to try reconnecting.
+    /// Only meaningful while `Lost`; always false otherwise.
+    pub fn due_for_retry(&mut self) -> bool {
+        match self.next_retry_at {
+            Some(at) if Instant::now() >= at => {
+                self.next_retry_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
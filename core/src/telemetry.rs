@@ -0,0 +1,191 @@
+//! MQTT telemetry bridge.
+//!
+//! Optionally mirrors `SensorReading`, `TapEvent`, `TapMetrics`, and
+//! `SessionStats` to an external MQTT broker, so a therapist can log
+//! multiple patients to a time-series store or home-monitoring dashboard
+//! without coupling Core to any particular storage backend.
+
+use crate::protocol::{Finger, SensorReading, SessionStats, TapEvent, TapMetrics};
+use rumqttc::{AsyncClient, Event, EventLoop, LastWill, MqttOptions, Packet, QoS};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::mpsc;
+
+#[derive(Error, Debug)]
+pub enum TelemetryError {
+    #[error("MQTT client error: {0}")]
+    Client(#[from] rumqttc::ClientError),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// An event mirrored to MQTT, one variant per branch of the topic map.
+#[derive(Debug, Clone)]
+pub enum TelemetryEvent {
+    Sensor(SensorReading),
+    Tap(TapEvent),
+    Metrics(TapMetrics),
+    SessionStats(SessionStats),
+}
+
+/// QoS for a single topic kind, kept distinct from `rumqttc::QoS` so
+/// `TelemetryConfig` callers don't need the crate in scope.
+#[derive(Debug, Clone, Copy)]
+pub enum TelemetryQos {
+    AtMostOnce,
+    AtLeastOnce,
+    ExactlyOnce,
+}
+
+impl From<TelemetryQos> for QoS {
+    fn from(qos: TelemetryQos) -> Self {
+        match qos {
+            TelemetryQos::AtMostOnce => QoS::AtMostOnce,
+            TelemetryQos::AtLeastOnce => QoS::AtLeastOnce,
+            TelemetryQos::ExactlyOnce => QoS::ExactlyOnce,
+        }
+    }
+}
+
+/// Per-topic-kind QoS. Sensor readings arrive at high rate so dropping one is
+/// fine; taps and session summaries are sparse and worth the delivery guarantee.
+#[derive(Debug, Clone, Copy)]
+pub struct TopicQos {
+    pub sensor: TelemetryQos,
+    pub tap: TelemetryQos,
+    pub metrics: TelemetryQos,
+    pub session: TelemetryQos,
+}
+
+impl Default for TopicQos {
+    fn default() -> Self {
+        Self {
+            sensor: TelemetryQos::AtMostOnce,
+            tap: TelemetryQos::AtLeastOnce,
+            metrics: TelemetryQos::AtLeastOnce,
+            session: TelemetryQos::AtLeastOnce,
+        }
+    }
+}
+
+/// Configuration for the MQTT telemetry bridge.
+#[derive(Clone)]
+pub struct TelemetryConfig {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    /// Identifies this patient/session in the topic map, e.g.
+    /// `tactilis/<session_id>/sensor/index`.
+    pub session_id: String,
+    pub qos: TopicQos,
+    pub keep_alive: Duration,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 1883,
+            client_id: "tactilis-core".to_string(),
+            session_id: "default".to_string(),
+            qos: TopicQos::default(),
+            keep_alive: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Handle to the MQTT telemetry bridge.
+pub struct TelemetryHandle {
+    sender: mpsc::Sender<TelemetryEvent>,
+    _task: tokio::task::JoinHandle<()>,
+}
+
+impl TelemetryHandle {
+    /// Mirrors an event to MQTT. Drops it silently if the publish task's
+    /// queue is full or it has already exited, since telemetry is a
+    /// best-effort side channel and must never back-pressure the UI thread.
+    pub fn publish(&self, event: TelemetryEvent) {
+        let _ = self.sender.try_send(event);
+    }
+}
+
+/// Starts the MQTT publish task for `config`, returning a handle whose
+/// `publish` mirrors events onto the broker's `tactilis/<session_id>/...`
+/// topic tree.
+///
+/// Announces an `offline` Last Will on `tactilis/<session_id>/status`,
+/// flipped to `online` once the connection handshake completes, so a
+/// dashboard can tell a dropped Core apart from one that's simply quiet.
+pub fn start(config: TelemetryConfig, runtime: &tokio::runtime::Handle) -> TelemetryHandle {
+    let status_topic = format!("tactilis/{}/status", config.session_id);
+
+    let mut mqtt_options = MqttOptions::new(config.client_id.clone(), config.host.clone(), config.port);
+    mqtt_options.set_keep_alive(config.keep_alive);
+    mqtt_options.set_last_will(LastWill::new(status_topic.clone(), "offline", QoS::AtLeastOnce, true));
+
+    let (client, eventloop) = AsyncClient::new(mqtt_options, 100);
+    let (sender, receiver) = mpsc::channel(256);
+
+    let task = runtime.spawn(run_publish_loop(client, eventloop, receiver, config, status_topic));
+
+    TelemetryHandle { sender, _task: task }
+}
+
+/// Drives the MQTT event loop (so pings/reconnects keep happening) while
+/// publishing whatever arrives on `receiver`, the same event stream the
+/// WebSocket/Unity layer is fed from.
+async fn run_publish_loop(
+    client: AsyncClient,
+    mut eventloop: EventLoop,
+    mut receiver: mpsc::Receiver<TelemetryEvent>,
+    config: TelemetryConfig,
+    status_topic: String,
+) {
+    loop {
+        tokio::select! {
+            event = receiver.recv() => {
+                let Some(event) = event else { break }; // Sender dropped
+                if let Err(e) = publish_event(&client, &config, &event).await {
+                    tracing::warn!("Failed to publish telemetry event: {}", e);
+                }
+            }
+            poll = eventloop.poll() => {
+                match poll {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        tracing::info!("MQTT telemetry connected, announcing online");
+                        if let Err(e) = client.publish(&status_topic, QoS::AtLeastOnce, true, "online").await {
+                            tracing::warn!("Failed to announce MQTT online status: {}", e);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::warn!("MQTT connection error: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    tracing::info!("MQTT telemetry task exiting");
+}
+
+/// Publishes a single event to its topic under `tactilis/<session_id>/...`,
+/// reusing the protocol types' existing `Serialize` impls for the JSON payload.
+async fn publish_event(client: &AsyncClient, config: &TelemetryConfig, event: &TelemetryEvent) -> Result<(), TelemetryError> {
+    let (topic_suffix, qos, payload) = match event {
+        TelemetryEvent::Sensor(reading) => {
+            let finger = match reading.finger {
+                Finger::Index => "index",
+                Finger::Middle => "middle",
+            };
+            (format!("sensor/{}", finger), config.qos.sensor, serde_json::to_vec(reading)?)
+        }
+        TelemetryEvent::Tap(tap) => ("tap".to_string(), config.qos.tap, serde_json::to_vec(tap)?),
+        TelemetryEvent::Metrics(metrics) => ("metrics".to_string(), config.qos.metrics, serde_json::to_vec(metrics)?),
+        TelemetryEvent::SessionStats(stats) => ("session".to_string(), config.qos.session, serde_json::to_vec(stats)?),
+    };
+
+    let topic = format!("tactilis/{}/{}", config.session_id, topic_suffix);
+    client.publish(topic, qos.into(), false, payload).await?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Toast notification overlay.
+//!
+//! The scrolling "Status Log" buries high-signal moments (a successful
+//! connect, a target hit, a dropped Unity client) in a wall of routine
+//! messages. `ToastStack` renders those separately as a stack of
+//! self-expiring, severity-colored popups, drawn on top of everything else
+//! at the end of `update()`.
+
+use eframe::egui;
+use std::time::{Duration, Instant};
+
+/// How long a toast stays on screen before it's dropped.
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    fn color(self) -> egui::Color32 {
+        match self {
+            ToastSeverity::Info => egui::Color32::LIGHT_BLUE,
+            ToastSeverity::Success => egui::Color32::LIGHT_GREEN,
+            ToastSeverity::Warning => egui::Color32::YELLOW,
+            ToastSeverity::Error => egui::Color32::LIGHT_RED,
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            ToastSeverity::Info => "â„¹",
+            ToastSeverity::Success => "âœ“",
+            ToastSeverity::Warning => "âš ",
+            ToastSeverity::Error => "âœ–",
+        }
+    }
+}
+
+struct Toast {
+    message: String,
+    severity: ToastSeverity,
+    expires_at: Instant,
+}
+
+/// A stack of active toasts, newest at the bottom. Owned by `TactilisApp`
+/// and drawn once per frame via `show`.
+#[derive(Default)]
+pub struct ToastStack {
+    toasts: Vec<Toast>,
+}
+
+impl ToastStack {
+    pub fn push(&mut self, message: impl Into<String>, severity: ToastSeverity) {
+        self.toasts.push(Toast {
+            message: message.into(),
+            severity,
+            expires_at: Instant::now() + TOAST_DURATION,
+        });
+    }
+
+    /// Drops expired toasts and draws the rest in the bottom-right corner.
+    pub fn show(&mut self, ctx: &egui::Context) {
+        let now = Instant::now();
+        self.toasts.retain(|toast| toast.expires_at > now);
+
+        egui::Area::new(egui::Id::new("toast_stack"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -12.0))
+            .show(ctx, |ui| {
+                for toast in &self.toasts {
+                    egui::Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.colored_label(toast.severity.color(), format!("{} {}", toast.severity.icon(), toast.message));
+                    });
+                    ui.add_space(4.0);
+                }
+            });
+    }
+}
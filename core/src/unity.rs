@@ -1,16 +1,40 @@
 //! Unity WebSocket communication handler.
 //!
 //! Runs a WebSocket server that Unity connects to. Handles bidirectional
-//! message passing between the core dashboard and the AR game.
+//! message passing between the core dashboard and the AR game. Multiple
+//! Unity clients (e.g. several AR headsets in a shared session) can be
+//! connected at once; each gets its own entry in `UnityServerState::peers`
+//! and can be addressed individually via `UnityServerHandle::send_to`.
 
 use crate::protocol::{CoreToUnityMessage, UnityToCoreMessage};
+use chrono::{DateTime, Utc};
 use futures_util::{SinkExt, StreamExt};
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
-use std::sync::Arc;
-use tokio::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
 use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio_rustls::TlsAcceptor;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
 use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
 use thiserror::Error;
+use uuid::Uuid;
+
+/// How long a newly-accepted connection has to send its version handshake
+/// before it's dropped. Separate from `heartbeat_timeout`, which only
+/// applies once a client has actually joined.
+const CLIENT_HELLO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Inclusive lower / exclusive upper bound of client protocol versions this
+/// server accepts, carried in the client's first message.
+const MIN_SUPPORTED_CLIENT_VERSION: f32 = 1.0;
+const MAX_SUPPORTED_CLIENT_VERSION: f32 = 2.0;
 
 #[derive(Error, Debug)]
 pub enum UnityError {
@@ -22,13 +46,35 @@ pub enum UnityError {
     Json(#[from] serde_json::Error),
     #[error("No Unity client connected")]
     NotConnected,
+    #[error("Unknown peer {0}")]
+    UnknownPeer(PeerId),
+    #[error("TLS error: {0}")]
+    Tls(String),
 }
 
+/// Identifies one connected Unity client for the lifetime of its connection.
+pub type PeerId = Uuid;
+
 /// Server configuration.
 #[derive(Clone)]
 pub struct UnityServerConfig {
     pub host: String,
     pub port: u16,
+    /// How often to ping each connected client to check it's still alive.
+    pub heartbeat_interval: Duration,
+    /// How long a client can go without sending us any frame (app message,
+    /// ping, or pong) before it's considered dead and disconnected. Frames
+    /// we send to it don't count.
+    pub heartbeat_timeout: Duration,
+    /// When set, serve `wss://` instead of plaintext `ws://`.
+    pub tls: Option<TlsConfig>,
+    /// Max number of `CoreToUnityMessage`s to hold in `UnityServerState`'s
+    /// replay buffer while no client is connected. Oldest entries are
+    /// dropped once it's full.
+    pub replay_buffer_capacity: usize,
+    /// When set, also bind a UDP side-channel alongside the WebSocket
+    /// server. Off by default.
+    pub udp: Option<UdpTransportConfig>,
 }
 
 impl Default for UnityServerConfig {
@@ -36,47 +82,249 @@ impl Default for UnityServerConfig {
         Self {
             host: "127.0.0.1".to_string(),
             port: 8765,
+            heartbeat_interval: Duration::from_secs(15),
+            heartbeat_timeout: Duration::from_secs(45),
+            tls: None,
+            replay_buffer_capacity: 200,
+            udp: None,
         }
     }
 }
 
-/// Shared state for the Unity server.
-pub struct UnityServerState {
-    pub connected: bool,
-    pub client_version: Option<String>,
+/// Configuration for the optional low-latency UDP transport: a plain
+/// datagram alternative to the WebSocket link for the press -> render loop,
+/// where TCP's round trip adds jitter AR work can't afford. Unlike the
+/// WebSocket side, there's no per-connection handshake — whichever address
+/// sent the most recent datagram is *the* UDP client, and every
+/// `CoreToUnityMessage` broadcast from then on is also mirrored there as a
+/// JSON datagram, same shape as the WebSocket text frames.
+#[derive(Debug, Clone, Copy)]
+pub struct UdpTransportConfig {
+    pub bind_addr: SocketAddr,
 }
 
-impl Default for UnityServerState {
+/// Cert/key pair for serving Unity connections over `wss://`, e.g. when the
+/// dashboard runs on a separate machine from the AR client.
+#[derive(Clone)]
+pub struct TlsConfig {
+    /// PEM-encoded certificate chain.
+    pub cert_path: PathBuf,
+    /// PEM-encoded private key, matching `cert_path`.
+    pub key_path: PathBuf,
+    /// How long to allow the TLS handshake to run before giving up, so a
+    /// stalled handshake can't leak a task.
+    pub handshake_timeout: Duration,
+}
+
+impl Default for TlsConfig {
     fn default() -> Self {
         Self {
-            connected: false,
-            client_version: None,
+            cert_path: PathBuf::from("cert.pem"),
+            key_path: PathBuf::from("key.pem"),
+            handshake_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Loads `tls.cert_path`/`tls.key_path` and builds the acceptor used to wrap
+/// each accepted `TcpStream` before the WebSocket handshake.
+fn build_tls_acceptor(tls: &TlsConfig) -> Result<TlsAcceptor, UnityError> {
+    let cert_file = std::fs::File::open(&tls.cert_path)?;
+    let certs = rustls_pemfile::certs(&mut std::io::BufReader::new(cert_file))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| UnityError::Tls(format!("failed to read cert chain from {}: {}", tls.cert_path.display(), e)))?;
+
+    let key_file = std::fs::File::open(&tls.key_path)?;
+    let key = rustls_pemfile::private_key(&mut std::io::BufReader::new(key_file))
+        .map_err(|e| UnityError::Tls(format!("failed to read private key from {}: {}", tls.key_path.display(), e)))?
+        .ok_or_else(|| UnityError::Tls(format!("no private key found in {}", tls.key_path.display())))?;
+
+    let server_config = tokio_rustls::rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| UnityError::Tls(e.to_string()))?;
+
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+/// Opcode byte prefixing a binary WebSocket frame, modeled on PTY-over-
+/// WebSocket framing: the first byte says how to interpret what follows,
+/// so a high-rate stream (continuous haptic/sensor data) can skip JSON
+/// serialization without abandoning it for control traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BinaryOpcode {
+    /// JSON control message, same shape as what `Message::Text` carries.
+    JsonControl = 0x00,
+    /// Packed little-endian `f32` sensor frame.
+    SensorFrame = 0x01,
+    /// Packed haptic command block.
+    HapticCommand = 0x02,
+}
+
+impl BinaryOpcode {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x00 => Some(Self::JsonControl),
+            0x01 => Some(Self::SensorFrame),
+            0x02 => Some(Self::HapticCommand),
+            _ => None,
+        }
+    }
+}
+
+/// Connection-health update about a peer, distinct from `UnityToCoreMessage`
+/// (which only ever carries what Unity itself sent): this is transport-level
+/// information the server derives from the ping/pong round trip, for the
+/// dashboard to show per-client latency or explain a disconnect.
+#[derive(Debug, Clone)]
+pub enum UnityPeerStatus {
+    /// Round-trip time measured from `peer`'s most recent pong.
+    Latency { peer: PeerId, latency_ms: u64 },
+    /// `peer` missed `heartbeat_timeout` and is being disconnected.
+    HeartbeatTimedOut { peer: PeerId },
+}
+
+/// Snapshot of one connected peer, for a multiplayer AR session to list
+/// connected devices or route a message to a specific player.
+#[derive(Debug, Clone)]
+pub struct PeerInfo {
+    pub id: PeerId,
+    pub addr: SocketAddr,
+    pub client_version: Option<String>,
+    pub joined_at: DateTime<Utc>,
+}
+
+/// A connected peer plus the channel its connection task listens on for
+/// messages addressed to it specifically (as opposed to the broadcast
+/// channel every peer subscribes to).
+struct Peer {
+    info: PeerInfo,
+    sender: mpsc::Sender<CoreToUnityMessage>,
+}
+
+/// Shared state for the Unity server: every client currently connected, plus
+/// whatever's been queued while none were.
+#[derive(Default)]
+pub struct UnityServerState {
+    peers: HashMap<PeerId, Peer>,
+    /// Messages sent via `UnityServerHandle::send` while `peers` was empty —
+    /// the broadcast channel silently drops a message with no subscribers,
+    /// so this is what keeps the initial game-state/config messages from
+    /// Unity's startup handshake alive until it connects. Drained, in order,
+    /// by the first connection's send task once one shows up.
+    replay_buffer: VecDeque<CoreToUnityMessage>,
+    replay_buffer_capacity: usize,
+}
+
+impl UnityServerState {
+    pub fn is_connected(&self) -> bool {
+        !self.peers.is_empty()
+    }
+
+    pub fn peers(&self) -> Vec<PeerInfo> {
+        self.peers.values().map(|p| p.info.clone()).collect()
+    }
+
+    fn buffer_for_replay(&mut self, msg: CoreToUnityMessage) {
+        self.replay_buffer.push_back(msg);
+        if self.replay_buffer.len() > self.replay_buffer_capacity {
+            self.replay_buffer.pop_front();
         }
     }
 }
 
+/// Whether `UnityServerHandle::send` reached a connected client directly or
+/// had to fall back to the replay buffer, so callers can tell the two apart
+/// instead of assuming delivery just because no error came back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendOutcome {
+    /// Broadcast to at least one connected client.
+    Delivered,
+    /// No client was connected; queued in `UnityServerState::replay_buffer`
+    /// to be replayed once one connects.
+    Buffered,
+}
+
 /// Handle to the Unity WebSocket server.
 pub struct UnityServerHandle {
-    /// Send messages to Unity
+    /// Send messages to every connected Unity client.
     pub sender: broadcast::Sender<CoreToUnityMessage>,
-    /// Receive messages from Unity
+    /// Opcode-framed binary fast path, for high-rate streams that shouldn't
+    /// pay JSON serialization cost. See `send_binary`.
+    binary_sender: broadcast::Sender<Vec<u8>>,
+    /// Receive messages from Unity (from any connected client).
     pub receiver: mpsc::Receiver<UnityToCoreMessage>,
+    /// Receive per-peer connection-health updates (latency, timeouts).
+    pub status_receiver: mpsc::Receiver<UnityPeerStatus>,
     /// Shared connection state
     pub state: Arc<RwLock<UnityServerState>>,
-    /// Shutdown signal
-    shutdown_tx: mpsc::Sender<()>,
+    /// Shutdown signal, broadcast so every live connection task can run its
+    /// own close handshake rather than just being dropped.
+    shutdown_tx: broadcast::Sender<()>,
 }
 
 impl UnityServerHandle {
-    /// Send a message to the connected Unity client.
-    pub fn send(&self, msg: CoreToUnityMessage) -> Result<(), UnityError> {
-        self.sender.send(msg).map_err(|_| UnityError::NotConnected)?;
+    /// Broadcast a message to every connected Unity client, or buffer it for
+    /// replay if none is connected right now — the broadcast channel itself
+    /// silently drops a message with no subscribers, so without this an
+    /// early `SessionStart` sent before Unity finishes its handshake would
+    /// just vanish.
+    ///
+    /// Only called from the synchronous egui thread (never from inside a
+    /// task on `runtime`), so blocking on the write lock here is safe and
+    /// much simpler than treating a momentarily-contended `try_write` as
+    /// "a client must be connected" — a peer connecting or disconnecting at
+    /// that instant used to fall through to a broadcast with no subscribers
+    /// and silently drop the message.
+    pub fn send(&self, msg: CoreToUnityMessage) -> Result<SendOutcome, UnityError> {
+        let mut state = self.state.blocking_write();
+        if !state.is_connected() {
+            state.buffer_for_replay(msg);
+            return Ok(SendOutcome::Buffered);
+        }
+        drop(state);
+
+        // The close handshake runs before `peers.remove` (see
+        // `handle_connection`), so `is_connected` can still read `true` for a
+        // peer whose broadcast subscriber was just dropped. Treat that as
+        // "came back around to disconnected" rather than losing the message.
+        if self.sender.send(msg.clone()).is_err() {
+            self.state.blocking_write().buffer_for_replay(msg);
+            return Ok(SendOutcome::Buffered);
+        }
+        Ok(SendOutcome::Delivered)
+    }
+
+    /// Send a message to one specific peer, e.g. to route a multiplayer AR
+    /// event to the player who should see it.
+    pub async fn send_to(&self, peer: PeerId, msg: CoreToUnityMessage) -> Result<(), UnityError> {
+        let state = self.state.read().await;
+        let peer = state.peers.get(&peer).ok_or(UnityError::UnknownPeer(peer))?;
+        peer.sender.send(msg).await.map_err(|_| UnityError::NotConnected)?;
+        Ok(())
+    }
+
+    /// Snapshot of every currently connected client.
+    pub async fn peers(&self) -> Vec<PeerInfo> {
+        self.state.read().await.peers()
+    }
+
+    /// Broadcast an opcode-framed binary message to every connected Unity
+    /// client, bypassing JSON serialization — for continuous haptic/sensor
+    /// streams where per-message overhead matters.
+    pub fn send_binary(&self, opcode: BinaryOpcode, payload: &[u8]) -> Result<(), UnityError> {
+        let mut frame = Vec::with_capacity(1 + payload.len());
+        frame.push(opcode as u8);
+        frame.extend_from_slice(payload);
+        self.binary_sender.send(frame).map_err(|_| UnityError::NotConnected)?;
         Ok(())
     }
 
-    /// Shutdown the server.
+    /// Shutdown the server, closing every live connection with a proper
+    /// WebSocket close handshake rather than just dropping the sockets.
     pub async fn shutdown(&self) {
-        let _ = self.shutdown_tx.send(()).await;
+        let _ = self.shutdown_tx.send(());
     }
 }
 
@@ -89,16 +337,32 @@ pub async fn start_server(
 ) -> Result<UnityServerHandle, UnityError> {
     let addr = format!("{}:{}", config.host, config.port);
     let listener = TcpListener::bind(&addr).await?;
-    tracing::info!("Unity WebSocket server listening on ws://{}", addr);
+
+    let tls_acceptor = config.tls.as_ref().map(build_tls_acceptor).transpose()?;
+    let handshake_timeout = config.tls.as_ref().map(|t| t.handshake_timeout);
+    tracing::info!(
+        "Unity WebSocket server listening on {}://{}",
+        if tls_acceptor.is_some() { "wss" } else { "ws" },
+        addr
+    );
 
     let (outgoing_tx, _) = broadcast::channel::<CoreToUnityMessage>(100);
+    let (binary_tx, _) = broadcast::channel::<Vec<u8>>(100);
     let (incoming_tx, incoming_rx) = mpsc::channel::<UnityToCoreMessage>(100);
-    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    let (status_tx, status_rx) = mpsc::channel::<UnityPeerStatus>(100);
+    let (shutdown_tx, mut shutdown_rx) = broadcast::channel::<()>(1);
 
-    let state = Arc::new(RwLock::new(UnityServerState::default()));
+    let state = Arc::new(RwLock::new(UnityServerState {
+        replay_buffer_capacity: config.replay_buffer_capacity,
+        ..Default::default()
+    }));
 
     let outgoing_tx_clone = outgoing_tx.clone();
+    let binary_tx_clone = binary_tx.clone();
     let state_clone = state.clone();
+    let shutdown_tx_clone = shutdown_tx.clone();
+    let heartbeat_interval = config.heartbeat_interval;
+    let heartbeat_timeout = config.heartbeat_timeout;
 
     runtime.spawn(async move {
         loop {
@@ -107,16 +371,31 @@ pub async fn start_server(
                     match result {
                         Ok((stream, addr)) => {
                             tracing::info!("Unity client connecting from: {}", addr);
+                            let peer_id = Uuid::new_v4();
                             let outgoing_rx = outgoing_tx_clone.subscribe();
+                            let binary_rx = binary_tx_clone.subscribe();
+                            let (direct_tx, direct_rx) = mpsc::channel::<CoreToUnityMessage>(100);
                             let incoming_tx = incoming_tx.clone();
+                            let status_tx = status_tx.clone();
                             let state = state_clone.clone();
+                            let conn_shutdown_rx = shutdown_tx_clone.subscribe();
 
-                            tokio::spawn(handle_connection(
+                            tokio::spawn(accept_and_handle(
                                 stream,
                                 addr,
+                                tls_acceptor.clone(),
+                                handshake_timeout,
+                                peer_id,
                                 outgoing_rx,
+                                binary_rx,
+                                direct_tx,
+                                direct_rx,
                                 incoming_tx,
+                                status_tx,
                                 state,
+                                conn_shutdown_rx,
+                                heartbeat_interval,
+                                heartbeat_timeout,
                             ));
                         }
                         Err(e) => {
@@ -132,22 +411,224 @@ pub async fn start_server(
         }
     });
 
+    if let Some(udp_config) = config.udp {
+        let socket = UdpSocket::bind(udp_config.bind_addr).await?;
+        tracing::info!("Unity UDP transport listening on {}", udp_config.bind_addr);
+        runtime.spawn(run_udp_transport(
+            socket,
+            outgoing_tx.subscribe(),
+            incoming_tx.clone(),
+            shutdown_tx.subscribe(),
+        ));
+    }
+
     Ok(UnityServerHandle {
         sender: outgoing_tx,
+        binary_sender: binary_tx,
         receiver: incoming_rx,
+        status_receiver: status_rx,
         state,
         shutdown_tx,
     })
 }
 
-async fn handle_connection(
+/// Mirrors every outgoing message onto a UDP side-channel, for a Unity
+/// client that wants the WebSocket link's reliability for control messages
+/// but prefers raw datagrams for the high-rate press/release stream. There's
+/// no handshake: the first datagram received from any address becomes the
+/// one `client_addr` mirrored traffic goes to, same as how a game server
+/// usually treats its first heard-from peer as "the" client in a
+/// single-player-bridge setup. A later datagram from a different address
+/// simply replaces it.
+async fn run_udp_transport(
+    socket: UdpSocket,
+    mut outgoing_rx: broadcast::Receiver<CoreToUnityMessage>,
+    incoming_tx: mpsc::Sender<UnityToCoreMessage>,
+    mut shutdown_rx: broadcast::Receiver<()>,
+) {
+    let mut client_addr: Option<SocketAddr> = None;
+    let mut buf = [0u8; 2048];
+    loop {
+        tokio::select! {
+            result = outgoing_rx.recv() => {
+                let Some(addr) = client_addr else { continue };
+                match result {
+                    Ok(msg) => match serde_json::to_string(&msg) {
+                        Ok(text) => {
+                            if let Err(e) = socket.send_to(text.as_bytes(), addr).await {
+                                tracing::warn!("Unity UDP send to {} failed: {}", addr, e);
+                            }
+                        }
+                        Err(e) => tracing::warn!("Failed to serialize message for UDP: {}", e),
+                    },
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        tracing::warn!("Unity UDP transport lagged, dropped {} messages", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            result = socket.recv_from(&mut buf) => {
+                match result {
+                    Ok((len, addr)) => {
+                        client_addr = Some(addr);
+                        match std::str::from_utf8(&buf[..len])
+                            .map_err(|e| e.to_string())
+                            .and_then(|text| serde_json::from_str::<UnityToCoreMessage>(text).map_err(|e| e.to_string()))
+                        {
+                            Ok(msg) => {
+                                if incoming_tx.send(msg).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Err(e) => tracing::warn!("Malformed UDP datagram from {}: {}", addr, e),
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Unity UDP recv failed: {}", e);
+                    }
+                }
+            }
+            _ = shutdown_rx.recv() => {
+                tracing::info!("Unity UDP transport shutting down");
+                break;
+            }
+        }
+    }
+}
+
+/// Wraps `stream` in TLS (with a handshake timeout, so a stalled handshake
+/// can't leak the spawned task) when the server is configured for `wss://`,
+/// then hands off to `handle_connection`. Plaintext connections skip
+/// straight to `handle_connection`.
+#[allow(clippy::too_many_arguments)]
+async fn accept_and_handle(
     stream: TcpStream,
     addr: SocketAddr,
-    mut outgoing_rx: broadcast::Receiver<CoreToUnityMessage>,
+    tls_acceptor: Option<TlsAcceptor>,
+    handshake_timeout: Option<Duration>,
+    peer_id: PeerId,
+    outgoing_rx: broadcast::Receiver<CoreToUnityMessage>,
+    binary_rx: broadcast::Receiver<Vec<u8>>,
+    direct_tx: mpsc::Sender<CoreToUnityMessage>,
+    direct_rx: mpsc::Receiver<CoreToUnityMessage>,
     incoming_tx: mpsc::Sender<UnityToCoreMessage>,
+    status_tx: mpsc::Sender<UnityPeerStatus>,
     state: Arc<RwLock<UnityServerState>>,
+    shutdown_rx: broadcast::Receiver<()>,
+    heartbeat_interval: Duration,
+    heartbeat_timeout: Duration,
 ) {
-    let ws_stream = match tokio_tungstenite::accept_async(stream).await {
+    match tls_acceptor {
+        Some(acceptor) => {
+            let timeout = handshake_timeout.unwrap_or(Duration::from_secs(10));
+            match tokio::time::timeout(timeout, acceptor.accept(stream)).await {
+                Ok(Ok(tls_stream)) => {
+                    handle_connection(
+                        tls_stream,
+                        addr,
+                        peer_id,
+                        outgoing_rx,
+                        binary_rx,
+                        direct_tx,
+                        direct_rx,
+                        incoming_tx,
+                        status_tx,
+                        state,
+                        shutdown_rx,
+                        heartbeat_interval,
+                        heartbeat_timeout,
+                    )
+                    .await;
+                }
+                Ok(Err(e)) => {
+                    tracing::error!("{}", UnityError::Tls(format!("handshake with {} failed: {}", addr, e)));
+                }
+                Err(_) => {
+                    tracing::error!("TLS handshake with {} timed out after {:?}", addr, timeout);
+                }
+            }
+        }
+        None => {
+            handle_connection(
+                stream,
+                addr,
+                peer_id,
+                outgoing_rx,
+                binary_rx,
+                direct_tx,
+                direct_rx,
+                incoming_tx,
+                status_tx,
+                state,
+                shutdown_rx,
+                heartbeat_interval,
+                heartbeat_timeout,
+            )
+            .await;
+        }
+    }
+}
+
+/// Waits for the client's very first frame and requires it to be a `Ready`
+/// message whose `client_version` parses as a float inside
+/// `[MIN_SUPPORTED_CLIENT_VERSION, MAX_SUPPORTED_CLIENT_VERSION)`. Runs
+/// before the peer is registered in `UnityServerState` or handed into the
+/// rest of `handle_connection`, so a client built against an incompatible
+/// wire shape is rejected outright instead of being treated as a normal
+/// connection and fed (or sent) messages it can't handle.
+async fn negotiate_client_version<S>(ws_stream: &mut WebSocketStream<S>) -> Result<f32, String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let frame = tokio::time::timeout(CLIENT_HELLO_TIMEOUT, ws_stream.next())
+        .await
+        .map_err(|_| "timed out waiting for version handshake".to_string())?
+        .ok_or_else(|| "connection closed before sending a handshake".to_string())?
+        .map_err(|e| format!("websocket error during handshake: {}", e))?;
+
+    let text = match frame {
+        Message::Text(text) => text,
+        other => return Err(format!("expected a text handshake frame, got {:?}", other)),
+    };
+
+    let msg: UnityToCoreMessage =
+        serde_json::from_str(&text).map_err(|e| format!("malformed handshake frame: {}", e))?;
+    let UnityToCoreMessage::Ready { client_version } = msg else {
+        return Err("first message must be Ready { client_version }".to_string());
+    };
+
+    let version: f32 = client_version
+        .parse()
+        .map_err(|_| format!("client_version {:?} is not a number", client_version))?;
+    if !(MIN_SUPPORTED_CLIENT_VERSION..MAX_SUPPORTED_CLIENT_VERSION).contains(&version) {
+        return Err(format!(
+            "client_version {} outside supported range [{}, {})",
+            version, MIN_SUPPORTED_CLIENT_VERSION, MAX_SUPPORTED_CLIENT_VERSION
+        ));
+    }
+
+    Ok(version)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_connection<S>(
+    stream: S,
+    addr: SocketAddr,
+    peer_id: PeerId,
+    mut outgoing_rx: broadcast::Receiver<CoreToUnityMessage>,
+    mut binary_rx: broadcast::Receiver<Vec<u8>>,
+    direct_tx: mpsc::Sender<CoreToUnityMessage>,
+    mut direct_rx: mpsc::Receiver<CoreToUnityMessage>,
+    incoming_tx: mpsc::Sender<UnityToCoreMessage>,
+    status_tx: mpsc::Sender<UnityPeerStatus>,
+    state: Arc<RwLock<UnityServerState>>,
+    mut shutdown_rx: broadcast::Receiver<()>,
+    heartbeat_interval: Duration,
+    heartbeat_timeout: Duration,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut ws_stream = match tokio_tungstenite::accept_async(stream).await {
         Ok(ws) => ws,
         Err(e) => {
             tracing::error!("WebSocket handshake failed for {}: {}", addr, e);
@@ -155,26 +636,138 @@ async fn handle_connection(
         }
     };
 
-    tracing::info!("Unity client connected: {}", addr);
-    {
+    let client_version = match negotiate_client_version(&mut ws_stream).await {
+        Ok(version) => version,
+        Err(reason) => {
+            tracing::warn!("Rejecting Unity client {} ({}): {}", peer_id, addr, reason);
+            let _ = ws_stream
+                .send(Message::Close(Some(CloseFrame { code: CloseCode::Policy, reason: reason.into() })))
+                .await;
+            let _ = ws_stream.close().await;
+            return;
+        }
+    };
+
+    tracing::info!("Unity client connected: {} ({}), protocol v{}", peer_id, addr, client_version);
+    // Drained in the same write lock as the peer registration so a `send`
+    // racing this connection lands in the buffer (and gets replayed below)
+    // or in the broadcast channel (and gets delivered normally), never both.
+    let replay_messages: Vec<CoreToUnityMessage> = {
         let mut state = state.write().await;
-        state.connected = true;
+        state.peers.insert(
+            peer_id,
+            Peer {
+                info: PeerInfo {
+                    id: peer_id,
+                    addr,
+                    client_version: Some(client_version.to_string()),
+                    joined_at: Utc::now(),
+                },
+                sender: direct_tx,
+            },
+        );
+        state.replay_buffer.drain(..).collect()
+    };
+
+    // The handshake frame doubles as Unity's usual `Ready` announcement, so
+    // forward it on now that the version's been validated - otherwise the
+    // rest of Core (e.g. `TactilisApp`'s "Unity client connected" toast)
+    // would never see it, since `negotiate_client_version` already consumed
+    // it off the stream.
+    if incoming_tx
+        .send(UnityToCoreMessage::Ready { client_version: client_version.to_string() })
+        .await
+        .is_err()
+    {
+        return;
     }
 
     let (mut ws_sender, mut ws_receiver) = ws_stream.split();
+    // Set when the peer's own close frame is what ended `recv_task`:
+    // tungstenite has already queued the reply close frame in that case, so
+    // the cleanup below must flush/close rather than send a second one.
+    let peer_closed = AtomicBool::new(false);
+    // Updated on every received frame, including pings/pongs, so the
+    // liveness check below only trips when Unity has stopped sending
+    // anything at all (even pongs to our heartbeat pings), not just when
+    // it stops sending app traffic.
+    let last_seen = Mutex::new(Instant::now());
 
-    // Task for sending messages to Unity
+    enum SendEvent {
+        Heartbeat,
+        Outgoing(CoreToUnityMessage),
+        Binary(Vec<u8>),
+    }
+
+    // Task for sending messages to Unity: broadcast traffic every peer gets,
+    // anything addressed to this peer specifically via `send_to`, and a
+    // periodic ping carrying the send timestamp so `recv_task` can compute
+    // round-trip latency from the matching pong.
     let send_task = async {
-        while let Ok(msg) = outgoing_rx.recv().await {
+        for msg in replay_messages {
             match serde_json::to_string(&msg) {
                 Ok(json) => {
                     if let Err(e) = ws_sender.send(Message::Text(json)).await {
-                        tracing::error!("Failed to send to Unity: {}", e);
+                        tracing::error!("Failed to replay buffered message to Unity client {}: {}", peer_id, e);
+                        return;
+                    }
+                }
+                Err(e) => tracing::error!("Failed to serialize buffered message: {}", e),
+            }
+        }
+
+        let mut heartbeat_ticker = tokio::time::interval(heartbeat_interval);
+        heartbeat_ticker.tick().await; // first tick fires immediately; skip it
+
+        loop {
+            let event = tokio::select! {
+                _ = heartbeat_ticker.tick() => SendEvent::Heartbeat,
+                result = outgoing_rx.recv() => match result {
+                    Ok(msg) => SendEvent::Outgoing(msg),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Unity client {} lagged, dropped {} messages", peer_id, skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                result = direct_rx.recv() => match result {
+                    Some(msg) => SendEvent::Outgoing(msg),
+                    None => break,
+                },
+                result = binary_rx.recv() => match result {
+                    Ok(frame) => SendEvent::Binary(frame),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Unity client {} lagged on binary stream, dropped {} frames", peer_id, skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            };
+
+            match event {
+                SendEvent::Heartbeat => {
+                    let payload = Utc::now().timestamp_millis().to_le_bytes().to_vec();
+                    if let Err(e) = ws_sender.send(Message::Ping(payload)).await {
+                        tracing::warn!("Failed to ping Unity client {}: {}", peer_id, e);
                         break;
                     }
                 }
-                Err(e) => {
-                    tracing::error!("Failed to serialize message: {}", e);
+                SendEvent::Outgoing(msg) => match serde_json::to_string(&msg) {
+                    Ok(json) => {
+                        if let Err(e) = ws_sender.send(Message::Text(json)).await {
+                            tracing::error!("Failed to send to Unity client {}: {}", peer_id, e);
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to serialize message: {}", e);
+                    }
+                },
+                SendEvent::Binary(frame) => {
+                    if let Err(e) = ws_sender.send(Message::Binary(frame)).await {
+                        tracing::error!("Failed to send binary frame to Unity client {}: {}", peer_id, e);
+                        break;
+                    }
                 }
             }
         }
@@ -183,14 +776,25 @@ async fn handle_connection(
     // Task for receiving messages from Unity
     let recv_task = async {
         while let Some(result) = ws_receiver.next().await {
-            match result {
-                Ok(Message::Text(text)) => {
+            let msg = match result {
+                Ok(msg) => msg,
+                Err(e) => {
+                    tracing::error!("WebSocket error: {}", e);
+                    break;
+                }
+            };
+            *last_seen.lock().unwrap() = Instant::now();
+
+            match msg {
+                Message::Text(text) => {
                     match serde_json::from_str::<UnityToCoreMessage>(&text) {
                         Ok(msg) => {
                             // Update state if it's a Ready message
                             if let UnityToCoreMessage::Ready { ref client_version } = msg {
                                 let mut state = state.write().await;
-                                state.client_version = Some(client_version.clone());
+                                if let Some(peer) = state.peers.get_mut(&peer_id) {
+                                    peer.info.client_version = Some(client_version.clone());
+                                }
                             }
 
                             if incoming_tx.send(msg).await.is_err() {
@@ -202,34 +806,116 @@ async fn handle_connection(
                         }
                     }
                 }
-                Ok(Message::Close(_)) => {
+                Message::Binary(data) => {
+                    let Some(opcode) = data.first().and_then(|b| BinaryOpcode::from_byte(*b)) else {
+                        tracing::warn!("Unity client {} sent binary frame with unknown opcode", peer_id);
+                        continue;
+                    };
+                    match opcode {
+                        BinaryOpcode::JsonControl => match std::str::from_utf8(&data[1..])
+                            .map_err(|e| e.to_string())
+                            .and_then(|text| serde_json::from_str::<UnityToCoreMessage>(text).map_err(|e| e.to_string()))
+                        {
+                            Ok(msg) => {
+                                if let UnityToCoreMessage::Ready { ref client_version } = msg {
+                                    let mut state = state.write().await;
+                                    if let Some(peer) = state.peers.get_mut(&peer_id) {
+                                        peer.info.client_version = Some(client_version.clone());
+                                    }
+                                }
+                                if incoming_tx.send(msg).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Err(e) => {
+                                tracing::warn!("Failed to parse binary JsonControl frame from Unity: {}", e);
+                            }
+                        },
+                        // Unity doesn't currently send sensor frames or haptic
+                        // commands upstream; these opcodes only flow
+                        // Core -> Unity via `send_binary`. Log rather than
+                        // inventing a receive-side protocol nothing uses yet.
+                        BinaryOpcode::SensorFrame | BinaryOpcode::HapticCommand => {
+                            tracing::debug!(
+                                "Ignoring unexpected {:?} binary frame from Unity client {}",
+                                opcode,
+                                peer_id
+                            );
+                        }
+                    }
+                }
+                Message::Close(_) => {
                     tracing::info!("Unity client closed connection");
+                    peer_closed.store(true, Ordering::Relaxed);
                     break;
                 }
-                Ok(Message::Ping(_)) => {
-                    // Pings handled automatically by tungstenite
+                Message::Ping(_) => {
+                    // Reply handled automatically by tungstenite
                 }
-                Ok(_) => {} // Ignore other message types
-                Err(e) => {
-                    tracing::error!("WebSocket error: {}", e);
-                    break;
+                Message::Pong(payload) => {
+                    if let Ok(bytes) = <[u8; 8]>::try_from(payload) {
+                        let sent_ms = i64::from_le_bytes(bytes);
+                        let latency_ms = (Utc::now().timestamp_millis() - sent_ms).max(0) as u64;
+                        let _ = status_tx.send(UnityPeerStatus::Latency { peer: peer_id, latency_ms }).await;
+                    }
                 }
+                _ => {} // Ignore other message types
+            }
+        }
+    };
+
+    // Checks the heartbeat deadline independently of whatever `send_task` and
+    // `recv_task` are doing, so a peer that's gone silent in both directions
+    // (e.g. lost Wi-Fi) is caught even if nothing else would have noticed.
+    let liveness_task = async {
+        let mut ticker = tokio::time::interval(heartbeat_interval);
+        loop {
+            ticker.tick().await;
+            let elapsed = last_seen.lock().unwrap().elapsed();
+            if elapsed > heartbeat_timeout {
+                tracing::warn!(
+                    "Unity client {} missed heartbeat (no frame for {:.0}s), disconnecting",
+                    peer_id,
+                    elapsed.as_secs_f32()
+                );
+                let _ = status_tx.send(UnityPeerStatus::HeartbeatTimedOut { peer: peer_id }).await;
+                break;
             }
         }
     };
 
-    // Run both tasks until one completes
+    // Run every task until one completes, or the server asks every
+    // connection to shut down.
     tokio::select! {
         _ = send_task => {}
         _ = recv_task => {}
+        _ = liveness_task => {}
+        _ = shutdown_rx.recv() => {
+            tracing::info!("Closing Unity connection {} for server shutdown", peer_id);
+        }
+    }
+
+    // Close handshake: if the peer already sent us a close frame,
+    // tungstenite has a reply queued internally, so just flush/close the
+    // sink. Otherwise we're the one initiating, so send a close frame first.
+    if peer_closed.load(Ordering::Relaxed) {
+        if let Err(e) = ws_sender.close().await {
+            tracing::debug!("Error flushing close reply to Unity client {}: {}", peer_id, e);
+        }
+    } else {
+        if let Err(e) = ws_sender.send(Message::Close(None)).await {
+            tracing::debug!("Error sending close frame to Unity client {}: {}", peer_id, e);
+        }
+        if let Err(e) = ws_sender.close().await {
+            tracing::debug!("Error closing Unity connection {}: {}", peer_id, e);
+        }
     }
 
     // Update state on disconnect
     {
         let mut state = state.write().await;
-        state.connected = false;
-        state.client_version = None;
+        state.peers.remove(&peer_id);
     }
 
-    tracing::info!("Unity client disconnected: {}", addr);
+    tracing::info!("Unity client disconnected: {} ({})", peer_id, addr);
 }